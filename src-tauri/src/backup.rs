@@ -0,0 +1,302 @@
+use crate::bundle::{archive, crypto, sha256_hex};
+use crate::database::{self, Pool};
+use crate::models::{BackupFile, BackupManifest};
+use crate::security;
+use rusqlite::backup::Backup;
+use rusqlite::Connection;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+pub const FORMAT: &str = "clipcrab-backup";
+pub const EXTENSION: &str = "ccbackup";
+
+const MANIFEST_FILE: &str = "manifest.json";
+const DB_FILE: &str = "clipboard.db.zst";
+const KEY_FILE: &str = "key.bin";
+// Parolayla şifrelenmiş anahtar
+const WRAPPED_KEY_FILE: &str = "key.sealed";
+// Zamanlanmış yedekler etkin profilin klasöründe tutulur
+const BACKUPS_DIR: &str = "backups";
+const COMPRESSION_LEVEL: i32 = 3;
+// Çevrimiçi yedekleme adım adım kopyalar; adımlar arasında yazanlar beklemez
+const PAGES_PER_STEP: i32 = 256;
+const STEP_PAUSE: Duration = Duration::from_millis(10);
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+fn now(conn: &Connection, format: &str) -> Result<String, String> {
+    conn.query_row("SELECT strftime(?1, 'now', 'localtime')", [format], |row| {
+        row.get(0)
+    })
+    .map_err(|e| format!("Query failed: {}", e))
+}
+
+/// Geçici dosyadaki veritabanının bütünlüğünü doğrula; şema sürümü ve öğe sayısı döner
+fn verify(conn: &Connection) -> Result<(i32, i64), String> {
+    let integrity: String = conn
+        .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to verify database: {}", e))?;
+    if integrity != "ok" {
+        return Err(format!("Database integrity check failed: {}", integrity));
+    }
+    let version: i32 = conn
+        .query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(|e| format!("Failed to read schema version: {}", e))?;
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM clipboard_history", [], |row| {
+            row.get(0)
+        })
+        .map_err(|e| format!("Not a ClipCrab database: {}", e))?;
+    Ok((version, count))
+}
+
+/// Etkin profilin veritabanını uygulama çalışırken tutarlı biçimde kopyala ve anahtarıyla tek dosyaya yaz.
+/// Parola verilirse anahtar onunla şifrelenir.
+pub fn create(
+    pool: &Pool,
+    path: &Path,
+    passphrase: Option<&str>,
+) -> Result<BackupManifest, String> {
+    let snapshot = database::profile_dir().join(format!("backup-{}.tmp", std::process::id()));
+    let result = write_backup(pool, path, passphrase, &snapshot);
+    fs::remove_file(&snapshot).ok();
+    result
+}
+
+fn write_backup(
+    pool: &Pool,
+    path: &Path,
+    passphrase: Option<&str>,
+    snapshot: &Path,
+) -> Result<BackupManifest, String> {
+    let conn = pool.get();
+    let created_at = now(&conn, "%Y-%m-%d %H:%M:%S")?;
+    {
+        let mut copy =
+            Connection::open(snapshot).map_err(|e| format!("Failed to create snapshot: {}", e))?;
+        Backup::new(&conn, &mut copy)
+            .and_then(|backup| backup.run_to_completion(PAGES_PER_STEP, STEP_PAUSE, None))
+            .map_err(|e| format!("Backup failed: {}", e))?;
+    }
+    let (schema_version, item_count) = verify(
+        &Connection::open(snapshot).map_err(|e| format!("Failed to open snapshot: {}", e))?,
+    )?;
+
+    let db = fs::read(snapshot).map_err(|e| format!("Failed to read snapshot: {}", e))?;
+    let db = zstd::bulk::compress(&db, COMPRESSION_LEVEL)
+        .map_err(|e| format!("Compression failed: {}", e))?;
+    let key = security::key_bytes();
+    let mut files = vec![(DB_FILE.to_string(), db)];
+    match passphrase {
+        Some(passphrase) => files.push((
+            WRAPPED_KEY_FILE.to_string(),
+            crypto::seal(passphrase, &key)?,
+        )),
+        None => files.push((KEY_FILE.to_string(), key)),
+    }
+
+    let manifest = BackupManifest {
+        format: FORMAT.to_string(),
+        schema_version,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at,
+        profile: crate::profiles::active_name(),
+        item_count,
+        key_wrapped: passphrase.is_some(),
+        checksums: files
+            .iter()
+            .map(|(name, data)| (name.clone(), sha256_hex(data)))
+            .collect(),
+    };
+    let manifest_json =
+        serde_json::to_vec_pretty(&manifest).map_err(|e| format!("JSON failed: {}", e))?;
+    files.insert(0, (MANIFEST_FILE.to_string(), manifest_json));
+
+    // Yarım yazılmış yedek bırakılmaz
+    let tmp = path.with_extension(format!("{}.tmp", EXTENSION));
+    fs::write(&tmp, archive::pack(&files)?)
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| {
+            fs::remove_file(&tmp).ok();
+            format!("Failed to write backup: {}", e)
+        })?;
+    Ok(manifest)
+}
+
+/// Yedeği doğrula (sağlamalar, bütünlük, şema sürümü, anahtar) ve etkin profilin yerine koy.
+/// Veritabanı çevrimiçi geri yüklenir; açık bağlantılar yeni içeriği görür.
+pub fn restore(
+    pool: &Pool,
+    path: &Path,
+    passphrase: Option<&str>,
+) -> Result<BackupManifest, String> {
+    let data = fs::read(path).map_err(|e| format!("Failed to read backup: {}", e))?;
+    let mut files = archive::unpack(&data)?;
+
+    let manifest: BackupManifest = files
+        .remove(MANIFEST_FILE)
+        .ok_or_else(|| "Not a ClipCrab backup".to_string())
+        .and_then(|data| {
+            serde_json::from_slice(&data).map_err(|e| format!("Invalid manifest: {}", e))
+        })?;
+    if manifest.format != FORMAT {
+        return Err(format!("Unknown backup format '{}'", manifest.format));
+    }
+    if manifest.schema_version > database::SCHEMA_VERSION {
+        return Err(format!(
+            "Backup schema version {} is newer than supported version {}",
+            manifest.schema_version,
+            database::SCHEMA_VERSION
+        ));
+    }
+    for (name, data) in &files {
+        match manifest.checksums.get(name) {
+            Some(expected) if *expected == sha256_hex(data) => {}
+            Some(_) => return Err(format!("Checksum mismatch for {}", name)),
+            None => return Err(format!("Unexpected file in backup: {}", name)),
+        }
+    }
+
+    let key = if manifest.key_wrapped {
+        let passphrase = passphrase.ok_or("This backup is protected by a passphrase")?;
+        let sealed = files
+            .remove(WRAPPED_KEY_FILE)
+            .ok_or("Backup encryption key is missing")?;
+        crypto::open(passphrase, &sealed)?
+    } else {
+        files
+            .remove(KEY_FILE)
+            .ok_or("Backup encryption key is missing")?
+    };
+    let db = files.remove(DB_FILE).ok_or("Backup database is missing")?;
+    let db = zstd::decode_all(db.as_slice()).map_err(|e| format!("Decompression failed: {}", e))?;
+
+    let staged = database::profile_dir().join(format!("restore-{}.tmp", std::process::id()));
+    let result = fs::write(&staged, db)
+        .map_err(|e| format!("Failed to stage backup: {}", e))
+        .and_then(|_| restore_staged(pool, &staged, &key, &manifest));
+    fs::remove_file(&staged).ok();
+    result.map(|_| manifest)
+}
+
+fn restore_staged(
+    pool: &Pool,
+    staged: &Path,
+    key: &[u8],
+    manifest: &BackupManifest,
+) -> Result<(), String> {
+    let source =
+        Connection::open(staged).map_err(|e| format!("Failed to open backup database: {}", e))?;
+    let (schema_version, item_count) = verify(&source)?;
+    if schema_version != manifest.schema_version || item_count != manifest.item_count {
+        return Err("Backup database does not match its manifest".to_string());
+    }
+
+    // Anahtar veritabanına ait değilse geçmiş okunamaz hale gelirdi
+    let sample: Option<String> = source
+        .query_row(
+            "SELECT content FROM clipboard_history WHERE is_encrypted = 1 LIMIT 1",
+            [],
+            |row| row.get(0),
+        )
+        .ok();
+    if sample.is_some_and(|sample| !security::key_opens(key, &sample)) {
+        return Err("Backup encryption key does not match its database".to_string());
+    }
+
+    // Geri yüklemeden önceki hali, yanlış yedek seçildiyse dönülebilsin diye saklanır
+    write_to_backups_dir(pool, "pre-restore")?;
+
+    pool.exclusive(|conn| {
+        let previous_key = security::key_bytes();
+        security::replace_key(key)?;
+        let restored = Backup::new(&source, conn)
+            .and_then(|backup| backup.run_to_completion(PAGES_PER_STEP, STEP_PAUSE, None))
+            .map_err(|e| format!("Restore failed: {}", e));
+        if let Err(e) = restored {
+            security::replace_key(&previous_key)?;
+            return Err(e);
+        }
+        // Eski sürümden gelen yedek bu sürümün şemasına taşınır
+        database::upgrade_schema(pool, conn);
+        Ok(())
+    })
+}
+
+fn backups_dir() -> PathBuf {
+    database::profile_dir().join(BACKUPS_DIR)
+}
+
+/// Etkin profilin zamanlanmış yedekleri, en yeniden eskiye
+pub fn list() -> Vec<BackupFile> {
+    let Ok(entries) = fs::read_dir(backups_dir()) else {
+        return Vec::new();
+    };
+    let mut backups: Vec<(SystemTime, BackupFile)> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == EXTENSION))
+        .filter_map(|entry| {
+            let meta = entry.metadata().ok()?;
+            Some((
+                meta.modified().ok()?,
+                BackupFile {
+                    name: entry.file_name().to_string_lossy().to_string(),
+                    path: entry.path().display().to_string(),
+                    size: meta.len(),
+                },
+            ))
+        })
+        .collect();
+    backups.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.name.cmp(&a.1.name)));
+    backups.into_iter().map(|(_, backup)| backup).collect()
+}
+
+fn write_to_backups_dir(pool: &Pool, prefix: &str) -> Result<PathBuf, String> {
+    let dir = backups_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backup directory: {}", e))?;
+    let stamp = now(&pool.get(), "%Y%m%d-%H%M%S")?;
+    let path = dir.join(format!("{}-{}.{}", prefix, stamp, EXTENSION));
+    create(pool, &path, None)?;
+    Ok(path)
+}
+
+/// Yedekleme klasörüne yeni bir yedek yaz ve `keep` sayısını aşan eski yedekleri sil
+pub fn create_rolling(pool: &Pool, keep: u32) -> Result<PathBuf, String> {
+    let path = write_to_backups_dir(pool, "backup")?;
+
+    for old in list().into_iter().skip(keep as usize) {
+        if let Err(e) = fs::remove_file(&old.path) {
+            eprintln!("Failed to remove old backup {}: {}", old.path, e);
+        }
+    }
+    Ok(path)
+}
+
+/// Son yedek ayarlanan aralıktan eskiyse yeni yedek al
+fn run_scheduled(pool: &Pool) {
+    let settings = crate::settings::load(&pool.get());
+    let Some(hours) = settings.backup_interval_hours else {
+        return;
+    };
+    let interval = Duration::from_secs(u64::from(hours) * 3600);
+    let latest = list()
+        .first()
+        .and_then(|backup| fs::metadata(&backup.path).and_then(|m| m.modified()).ok());
+    if latest.is_some_and(|time| time.elapsed().is_ok_and(|age| age < interval)) {
+        return;
+    }
+
+    match create_rolling(pool, settings.backup_keep) {
+        Ok(path) => println!("Scheduled backup written to {}", path.display()),
+        Err(e) => eprintln!("Scheduled backup failed: {}", e),
+    }
+}
+
+/// Zamanlanmış yedeklemeyi başlat; panoyu izleyen süreçte (arayüz veya daemon) bir kez çağrılır
+pub fn start_scheduler(pool: Pool) {
+    thread::spawn(move || loop {
+        run_scheduled(&pool);
+        thread::sleep(SCHEDULE_CHECK_INTERVAL);
+    });
+}
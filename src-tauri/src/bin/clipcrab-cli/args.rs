@@ -0,0 +1,88 @@
+use std::collections::{HashMap, HashSet};
+
+// Değer alan seçenekler; diğer "--" ile başlayanlar bayrak kabul edilir
+const VALUE_OPTIONS: &[&str] = &[
+    "limit", "offset", "output", "type", "tag", "app", "format", "query", "data-dir",
+];
+
+/// Basit komut satırı ayrıştırıcı: `komut [konumsal...] [--seçenek değer] [--bayrak]`
+pub struct Args {
+    pub command: Option<String>,
+    pub positional: Vec<String>,
+    options: HashMap<String, String>,
+    flags: HashSet<String>,
+}
+
+impl Args {
+    pub fn parse(raw: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = Args {
+            command: None,
+            positional: Vec::new(),
+            options: HashMap::new(),
+            flags: HashSet::new(),
+        };
+
+        let mut raw = raw.into_iter();
+        while let Some(arg) = raw.next() {
+            let name = match arg.as_str() {
+                "-o" => Some("output".to_string()),
+                "-n" => Some("limit".to_string()),
+                "-h" => Some("help".to_string()),
+                "--" => {
+                    args.positional.extend(raw.by_ref());
+                    break;
+                }
+                _ => arg.strip_prefix("--").map(str::to_string),
+            };
+
+            let Some(name) = name else {
+                if args.command.is_none() {
+                    args.command = Some(arg);
+                } else {
+                    args.positional.push(arg);
+                }
+                continue;
+            };
+
+            // --seçenek=değer biçimi
+            if let Some((key, value)) = name.split_once('=') {
+                args.options.insert(key.to_string(), value.to_string());
+            } else if VALUE_OPTIONS.contains(&name.as_str()) {
+                let value = raw
+                    .next()
+                    .ok_or_else(|| format!("Option --{} requires a value", name))?;
+                args.options.insert(name, value);
+            } else {
+                args.flags.insert(name);
+            }
+        }
+
+        Ok(args)
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.contains(name)
+    }
+
+    pub fn number(&self, name: &str) -> Result<Option<i32>, String> {
+        self.option(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|_| format!("--{} must be a number", name))
+            })
+            .transpose()
+    }
+
+    /// Konumsal öğe kimliği; fzf satırı ("12\tönizleme") da kabul edilir
+    pub fn id(&self, index: usize) -> Result<i64, String> {
+        let value = self.positional.get(index).ok_or("Missing item id")?.trim();
+        let id = value.split(['\t', ' ']).next().unwrap_or_default();
+        id.parse()
+            .map_err(|_| format!("Invalid item id: {}", value))
+    }
+}
@@ -0,0 +1,221 @@
+mod args;
+mod output;
+#[cfg(unix)]
+mod rpc;
+
+use args::Args;
+use clipcrab_lib::commands::{self, Transfer};
+use clipcrab_lib::database::{self, Pool};
+use clipcrab_lib::export::{self, ExportFormat};
+use clipcrab_lib::models::ExportFilter;
+use output::OutputMode;
+use std::io::{self, BufWriter, Read};
+use std::path::Path;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: clipcrab-cli <command> [options]
+
+Commands:
+  list                    List recent items (pinned first)
+  search <query>          Search item contents
+  get <id>                Print an item (images are written as PNG)
+  copy <id>               Put an item back on the clipboard
+  pin <id>                Toggle the pinned state of an item
+  rm <id>...              Move items to the trash
+  add                     Add text read from stdin
+  export [path]           Export history (to stdout when no path is given)
+  rpc <method> [params]   Call the running app over its socket (list, search, get,
+                          copy, add, pin, delete, subscribe); params are JSON
+
+Options:
+  -o, --output <mode>     table, json, raw or fzf (default: table; raw for get)
+      --fzf               Same as --output fzf: one \"id<TAB>preview\" line per item
+  -n, --limit <n>         Number of items to list (0 = all)
+      --offset <n>        Skip the first n items
+      --type <types>      Comma separated categories: text, url, email, code, image
+      --tag <tags>        Comma separated tag names (items must have all)
+      --app <name>        Source application
+      --query <text>      Text filter for export
+      --format <format>   Export format: json, ndjson, archive, csv, markdown, html, folder
+      --data-dir <path>   Use another data directory (also CLIPCRAB_DATA_DIR)
+      --portable          Use the clipcrab-data directory next to the executable
+
+Example:
+  clipcrab-cli copy \"$(clipcrab-cli list --fzf -n 0 | fzf)\"
+";
+
+fn main() -> ExitCode {
+    let raw: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(e) = database::configure_data_dir(&raw) {
+        eprintln!("clipcrab-cli: {}", e);
+        return ExitCode::from(2);
+    }
+
+    let args = match Args::parse(raw) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("clipcrab-cli: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    let Some(command) = args.command.clone().filter(|_| !args.flag("help")) else {
+        print!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    };
+
+    match run(&command, &args) {
+        Ok(()) => ExitCode::SUCCESS,
+        // Çıktı `head` gibi bir komuta bağlanıp erkenden kapatıldıysa hata sayılmaz
+        Err(e) if e.contains("Broken pipe") => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("clipcrab-cli: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn output_mode(args: &Args, default: OutputMode) -> Result<OutputMode, String> {
+    if args.flag("fzf") {
+        return Ok(OutputMode::Fzf);
+    }
+    args.option("output")
+        .map(OutputMode::parse)
+        .unwrap_or(Ok(default))
+}
+
+fn run(command: &str, args: &Args) -> Result<(), String> {
+    let pool = Pool::new();
+    match command {
+        "list" => {
+            let mode = output_mode(args, OutputMode::Table)?;
+            let items = commands::get_clipboard_history_sync(
+                &pool,
+                args.number("limit")?,
+                args.number("offset")?,
+            );
+            output::print_items(&items, mode).map_err(|e| e.to_string())
+        }
+        "search" => {
+            let mode = output_mode(args, OutputMode::Table)?;
+            let query = args.positional.join(" ");
+            let items = commands::search_clipboard_history_sync(
+                &pool,
+                query,
+                args.number("limit")?,
+                args.number("offset")?,
+                args.option("type").map(str::to_string),
+                args.option("tag").map(str::to_string),
+                args.option("app").map(str::to_string),
+            );
+            output::print_items(&items, mode).map_err(|e| e.to_string())
+        }
+        "get" => {
+            let mode = output_mode(args, OutputMode::Raw)?;
+            let id = args.id(0)?;
+            let conn = pool.get();
+            let item =
+                commands::get_item(&conn, id)?.ok_or_else(|| format!("Item {} not found", id))?;
+            output::print_item(&item, mode)
+        }
+        "copy" => copy(&pool, args),
+        "pin" => {
+            let id = args.id(0)?;
+            let pinned = pool.write(|conn| commands::toggle_pin_item(conn, id))?;
+            println!("{} {}", if pinned { "Pinned" } else { "Unpinned" }, id);
+            Ok(())
+        }
+        "rm" => {
+            if args.positional.is_empty() {
+                return Err("Missing item id".to_string());
+            }
+            // Aynı komutla silinenler birlikte geri alınabilir
+            let undo_token = commands::new_undo_token();
+            for index in 0..args.positional.len() {
+                let id = args.id(index)?;
+                if !pool.write(|conn| commands::delete_item(conn, id, &undo_token))? {
+                    return Err(format!("Item {} not found", id));
+                }
+            }
+            Ok(())
+        }
+        "add" => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|e| format!("Failed to read stdin: {}", e))?;
+            // `echo` ile gelen sondaki satır sonu içeriğe dahil edilmez
+            let text = text
+                .strip_suffix('\n')
+                .map(|rest| rest.strip_suffix('\r').unwrap_or(rest))
+                .unwrap_or(&text);
+            let id = pool.write(|conn| commands::add_text_item(conn, text, args.option("app")))?;
+            println!("{}", id);
+            Ok(())
+        }
+        "export" => export(&pool, args),
+        #[cfg(unix)]
+        "rpc" => {
+            let method = args.positional.first().ok_or("Missing RPC method")?;
+            rpc::call(method, args.positional.get(1).map(String::as_str))
+        }
+        other => Err(format!("Unknown command: {} (see --help)", other)),
+    }
+}
+
+/// Linux'ta pano içeriği yazan süreçle yaşar; bu yüzden içerik arka plandaki
+/// bir kopyaya (`--foreground`) yazdırılır ve o, başka bir uygulama panoyu alana kadar bekler
+fn copy(pool: &Pool, args: &Args) -> Result<(), String> {
+    let id = args.id(0)?;
+
+    if cfg!(target_os = "linux") && !args.flag("foreground") {
+        let conn = pool.get();
+        if commands::get_item(&conn, id)?.is_none() {
+            return Err(format!("Item {} not found", id));
+        }
+
+        let exe = std::env::current_exe().map_err(|e| e.to_string())?;
+        std::process::Command::new(exe)
+            .args(["copy", &id.to_string(), "--foreground"])
+            .env(database::DATA_DIR_ENV, database::data_dir())
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to start clipboard owner: {}", e))?;
+        return Ok(());
+    }
+
+    commands::copy_item(pool, id, true)
+}
+
+fn export(pool: &Pool, args: &Args) -> Result<(), String> {
+    let format = ExportFormat::parse(args.option("format").unwrap_or("json"))?;
+    let filter = ExportFilter {
+        query: args.option("query").map(str::to_string),
+        content_filter: args.option("type").map(str::to_string),
+        tag_filter: args.option("tag").map(str::to_string),
+        source_app: args.option("app").map(str::to_string),
+    };
+
+    let mut writer = match args.positional.first() {
+        Some(path) => export::create_writer(format, Path::new(path))?,
+        None => export::create_stream_writer(format, BufWriter::new(io::stdout()))?,
+    };
+
+    let conn = pool.get();
+    let report = commands::stream_items(
+        &conn,
+        &filter,
+        writer.as_mut(),
+        &mut Transfer::begin(None, "export"),
+    )?;
+    eprintln!("Exported {} items", report.exported);
+    if report.skipped > 0 {
+        eprintln!(
+            "Skipped {} items that could not be decrypted",
+            report.skipped
+        );
+    }
+    Ok(())
+}
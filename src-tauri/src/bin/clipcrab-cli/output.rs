@@ -0,0 +1,125 @@
+use base64::{engine::general_purpose, Engine as _};
+use clipcrab_lib::models::ClipboardItem;
+use std::io::{self, Write};
+
+const PREVIEW_LENGTH: usize = 60;
+const HIDDEN_PREVIEW: &str = "••••••••";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    Table,
+    Json,
+    Raw,
+    /// Satır başına "id<TAB>önizleme"; fzf ile seçip `cut -f1` ile kimlik alınır
+    Fzf,
+}
+
+impl OutputMode {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "raw" => Ok(Self::Raw),
+            "fzf" => Ok(Self::Fzf),
+            other => Err(format!("Unknown output mode: {}", other)),
+        }
+    }
+}
+
+/// Tek satırlık, kısaltılmış önizleme; hassas öğeler gizlenir
+fn preview(item: &ClipboardItem, max_chars: usize) -> String {
+    if item.sensitive {
+        return HIDDEN_PREVIEW.to_string();
+    }
+    let line: String = item
+        .content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if line.chars().count() > max_chars {
+        let cut: String = line.chars().take(max_chars.saturating_sub(1)).collect();
+        format!("{}…", cut)
+    } else {
+        line
+    }
+}
+
+fn flags(item: &ClipboardItem) -> String {
+    format!(
+        "{}{}",
+        if item.pinned { "P" } else { "-" },
+        if item.sensitive { "S" } else { "-" }
+    )
+}
+
+pub fn print_items(items: &[ClipboardItem], mode: OutputMode) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    match mode {
+        OutputMode::Json => {
+            serde_json::to_writer_pretty(&mut out, items)?;
+            writeln!(out)?;
+        }
+        OutputMode::Raw => {
+            for item in items {
+                writeln!(out, "{}", item.content)?;
+            }
+        }
+        OutputMode::Fzf => {
+            for item in items {
+                writeln!(out, "{}\t{}", item.id, preview(item, usize::MAX))?;
+            }
+        }
+        OutputMode::Table => {
+            let id_width = items
+                .iter()
+                .map(|item| item.id.to_string().len())
+                .max()
+                .unwrap_or(2)
+                .max(2);
+            writeln!(
+                out,
+                "{:>id_width$}  {:<19}  {:<6}  {:<2}  PREVIEW",
+                "ID", "CREATED", "TYPE", "FL"
+            )?;
+            for item in items {
+                writeln!(
+                    out,
+                    "{:>id_width$}  {:<19}  {:<6}  {:<2}  {}",
+                    item.id,
+                    item.created_at,
+                    item.category,
+                    flags(item),
+                    preview(item, PREVIEW_LENGTH)
+                )?;
+            }
+        }
+    }
+    out.flush()
+}
+
+/// Tek öğe: ham çıktıda metin olduğu gibi, resim PNG baytları olarak yazılır
+pub fn print_item(item: &ClipboardItem, mode: OutputMode) -> Result<(), String> {
+    let mut out = io::stdout().lock();
+    match mode {
+        OutputMode::Raw => match item.image_data.as_deref() {
+            Some(data) if item.content_type == "image" => {
+                let png = general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(|e| format!("Invalid image data: {}", e))?;
+                out.write_all(&png).map_err(|e| e.to_string())?;
+            }
+            _ => out
+                .write_all(item.content.as_bytes())
+                .map_err(|e| e.to_string())?,
+        },
+        OutputMode::Json => {
+            serde_json::to_writer_pretty(&mut out, item).map_err(|e| e.to_string())?;
+            writeln!(out).map_err(|e| e.to_string())?;
+        }
+        OutputMode::Table | OutputMode::Fzf => {
+            drop(out);
+            return print_items(std::slice::from_ref(item), mode).map_err(|e| e.to_string());
+        }
+    }
+    out.flush().map_err(|e| e.to_string())
+}
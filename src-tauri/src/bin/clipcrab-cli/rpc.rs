@@ -0,0 +1,56 @@
+use clipcrab_lib::ipc::{self, Request, Response};
+use serde_json::Value;
+use std::io::{BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+
+/// Çalışan ClipCrab'a tek bir JSON-RPC isteği gönder; `subscribe` için bildirimleri akıt
+pub fn call(method: &str, params: Option<&str>) -> Result<(), String> {
+    let params = match params {
+        Some(raw) => {
+            serde_json::from_str(raw).map_err(|e| format!("Invalid params JSON: {}", e))?
+        }
+        None => Value::Null,
+    };
+
+    let path = ipc::socket_path();
+    let mut stream = UnixStream::connect(&path).map_err(|e| {
+        format!(
+            "Cannot connect to {} (is ClipCrab running?): {}",
+            path.display(),
+            e
+        )
+    })?;
+
+    let request = Request {
+        jsonrpc: "2.0".to_string(),
+        method: method.to_string(),
+        params,
+        id: Some(Value::from(1)),
+    };
+    let mut line = serde_json::to_vec(&request).map_err(|e| e.to_string())?;
+    line.push(b'\n');
+    stream.write_all(&line).map_err(|e| e.to_string())?;
+
+    let reader = BufReader::new(stream);
+    let mut stdout = std::io::stdout().lock();
+    for line in reader.lines() {
+        let line = line.map_err(|e| e.to_string())?;
+
+        // Aboneliklerde gelen bildirimler olduğu gibi yazılır
+        let Ok(response) = serde_json::from_str::<Response>(&line) else {
+            writeln!(stdout, "{}", line).map_err(|e| e.to_string())?;
+            continue;
+        };
+        if let Some(error) = response.error {
+            return Err(format!("{} ({})", error.message, error.code));
+        }
+
+        let result = response.result.unwrap_or(Value::Null);
+        let pretty = serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?;
+        writeln!(stdout, "{}", pretty).map_err(|e| e.to_string())?;
+        if method != "subscribe" {
+            break;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+/// Dosyaları bellekte tar arşivine yaz
+pub fn pack(files: &[(String, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    let mut builder = tar::Builder::new(Vec::new());
+    for (name, data) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o600);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, data.as_slice())
+            .map_err(|e| format!("Failed to add {} to archive: {}", name, e))?;
+    }
+    builder
+        .into_inner()
+        .map_err(|e| format!("Failed to finish archive: {}", e))
+}
+
+/// Arşivdeki dosyaları ada göre oku (diske hiçbir şey çıkarılmaz)
+pub fn unpack(data: &[u8]) -> Result<HashMap<String, Vec<u8>>, String> {
+    let mut archive = tar::Archive::new(data);
+    let mut files = HashMap::new();
+
+    let entries = archive
+        .entries()
+        .map_err(|e| format!("Invalid archive: {}", e))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Invalid archive entry: {}", e))?;
+        let name = entry
+            .path()
+            .map_err(|e| format!("Invalid archive entry: {}", e))?
+            .to_string_lossy()
+            .to_string();
+        let mut content = Vec::new();
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+        files.insert(name, content);
+    }
+
+    Ok(files)
+}
@@ -0,0 +1,110 @@
+use aes_gcm::aead::{rand_core::RngCore, Aead, KeyInit, OsRng, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use sha2::Sha256;
+
+// Dosya başlığı: MAGIC + sürüm + PBKDF2 tur sayısı + tuz + nonce
+const MAGIC: &[u8; 8] = b"CCBUNDLE";
+const ENVELOPE_VERSION: u8 = 1;
+const PBKDF2_ROUNDS: u32 = 600_000;
+// Başlıktaki tur sayısı dosyadan gelir; bu aralık dışı reddedilir (aşırı değer içe aktarmayı kilitlerdi)
+const MAX_PBKDF2_ROUNDS: u32 = 10 * PBKDF2_ROUNDS;
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+const HEADER_SIZE: usize = MAGIC.len() + 1 + 4 + SALT_SIZE;
+
+fn derive_key(passphrase: &str, salt: &[u8], rounds: u32) -> Key<Aes256Gcm> {
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, rounds, &mut key);
+    key.into()
+}
+
+/// Veriyi paroladan türetilen anahtarla şifrele (başlık AAD olarak doğrulanır)
+pub fn seal(passphrase: &str, plain: &[u8]) -> Result<Vec<u8>, String> {
+    let mut salt = [0u8; SALT_SIZE];
+    let mut nonce_bytes = [0u8; NONCE_SIZE];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce_bytes);
+
+    let mut out = Vec::with_capacity(HEADER_SIZE + NONCE_SIZE + plain.len() + 16);
+    out.extend_from_slice(MAGIC);
+    out.push(ENVELOPE_VERSION);
+    out.extend_from_slice(&PBKDF2_ROUNDS.to_le_bytes());
+    out.extend_from_slice(&salt);
+
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, &salt, PBKDF2_ROUNDS));
+    let ciphertext = cipher
+        .encrypt(
+            Nonce::from_slice(&nonce_bytes),
+            Payload {
+                msg: plain,
+                aad: &out,
+            },
+        )
+        .map_err(|e| format!("Encryption failed: {}", e))?;
+
+    out.extend_from_slice(&nonce_bytes);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+pub fn open(passphrase: &str, data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < HEADER_SIZE + NONCE_SIZE || &data[..MAGIC.len()] != MAGIC {
+        return Err("Not a ClipCrab bundle".to_string());
+    }
+    if data[MAGIC.len()] != ENVELOPE_VERSION {
+        return Err(format!(
+            "Unsupported bundle envelope version {}",
+            data[MAGIC.len()]
+        ));
+    }
+
+    let (header, rest) = data.split_at(HEADER_SIZE);
+    let rounds = u32::from_le_bytes(header[MAGIC.len() + 1..MAGIC.len() + 5].try_into().unwrap());
+    if !(PBKDF2_ROUNDS..=MAX_PBKDF2_ROUNDS).contains(&rounds) {
+        return Err(format!("Unsupported key derivation rounds {}", rounds));
+    }
+    let salt = &header[MAGIC.len() + 5..];
+    let (nonce_bytes, ciphertext) = rest.split_at(NONCE_SIZE);
+
+    let cipher = Aes256Gcm::new(&derive_key(passphrase, salt, rounds));
+    cipher
+        .decrypt(
+            Nonce::from_slice(nonce_bytes),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| "Wrong passphrase or corrupted bundle".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn envelope(rounds: u32) -> Vec<u8> {
+        let mut data = MAGIC.to_vec();
+        data.push(ENVELOPE_VERSION);
+        data.extend_from_slice(&rounds.to_le_bytes());
+        data.extend_from_slice(&[0u8; SALT_SIZE + NONCE_SIZE + 16]);
+        data
+    }
+
+    #[test]
+    fn open_rejects_out_of_range_rounds_before_deriving() {
+        for rounds in [0, 1, PBKDF2_ROUNDS - 1, MAX_PBKDF2_ROUNDS + 1, u32::MAX] {
+            assert_eq!(
+                open("secret", &envelope(rounds)).unwrap_err(),
+                format!("Unsupported key derivation rounds {}", rounds)
+            );
+        }
+    }
+
+    #[test]
+    fn open_rejects_foreign_data() {
+        assert!(open("secret", b"not a bundle").is_err());
+        let mut data = envelope(PBKDF2_ROUNDS);
+        data[MAGIC.len()] = ENVELOPE_VERSION + 1;
+        assert!(open("secret", &data).is_err());
+    }
+}
@@ -0,0 +1,191 @@
+pub(crate) mod archive;
+pub(crate) mod crypto;
+
+use crate::models::{BundleManifest, ClipboardItem};
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+pub const FORMAT: &str = "clipcrab-bundle";
+/// Arşiv içeriğinin biçim sürümü (öğe alanları değiştiğinde artırılır)
+pub const SCHEMA_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const ITEMS_FILE: &str = "items.json";
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Arşivdeki öğe; resim verisi ayrı dosyada tutulur
+#[derive(Serialize, Deserialize)]
+struct BundleItem {
+    content: String,
+    content_type: String,
+    category: String,
+    image: Option<String>,
+    created_at: String,
+    pinned: bool,
+    sensitive: bool,
+    source_app: Option<String>,
+    tags: Vec<String>,
+}
+
+pub(crate) fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Çözülmüş öğeleri parolayla şifrelenmiş tek dosyaya yaz
+pub fn write(
+    path: &Path,
+    passphrase: &str,
+    created_at: String,
+    items: Vec<ClipboardItem>,
+    skipped_count: usize,
+) -> Result<BundleManifest, String> {
+    let mut files: Vec<(String, Vec<u8>)> = Vec::new();
+    let mut bundle_items = Vec::with_capacity(items.len());
+
+    for (index, item) in items.into_iter().enumerate() {
+        let image = match item.image_data.filter(|data| !data.is_empty()) {
+            Some(data) => {
+                let png = general_purpose::STANDARD
+                    .decode(&data)
+                    .map_err(|e| format!("Invalid image data: {}", e))?;
+                let name = format!("images/{}.png", index + 1);
+                files.push((name.clone(), png));
+                Some(name)
+            }
+            None => None,
+        };
+
+        bundle_items.push(BundleItem {
+            content: item.content,
+            content_type: item.content_type,
+            category: item.category,
+            image,
+            created_at: item.created_at,
+            pinned: item.pinned,
+            sensitive: item.sensitive,
+            source_app: item.source_app,
+            tags: item.tags,
+        });
+    }
+
+    let items_json =
+        serde_json::to_vec(&bundle_items).map_err(|e| format!("JSON failed: {}", e))?;
+    files.push((ITEMS_FILE.to_string(), items_json));
+
+    let manifest = BundleManifest {
+        format: FORMAT.to_string(),
+        schema_version: SCHEMA_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at,
+        item_count: bundle_items.len(),
+        image_count: files.len() - 1,
+        skipped_count,
+        checksums: files
+            .iter()
+            .map(|(name, data)| (name.clone(), sha256_hex(data)))
+            .collect::<BTreeMap<_, _>>(),
+    };
+    let manifest_json =
+        serde_json::to_vec_pretty(&manifest).map_err(|e| format!("JSON failed: {}", e))?;
+    files.insert(0, (MANIFEST_FILE.to_string(), manifest_json));
+
+    let tar = archive::pack(&files)?;
+    let compressed = zstd::bulk::compress(&tar, COMPRESSION_LEVEL)
+        .map_err(|e| format!("Compression failed: {}", e))?;
+    let sealed = crypto::seal(passphrase, &compressed)?;
+
+    std::fs::write(path, sealed).map_err(|e| format!("Failed to write bundle: {}", e))?;
+    Ok(manifest)
+}
+
+/// Arşivi aç, manifest ve sağlama toplamlarını doğrula, öğeleri döndür
+pub fn read(path: &Path, passphrase: &str) -> Result<(BundleManifest, Vec<ClipboardItem>), String> {
+    let sealed = std::fs::read(path).map_err(|e| format!("Failed to read bundle: {}", e))?;
+    let compressed = crypto::open(passphrase, &sealed)?;
+    let tar = zstd::decode_all(compressed.as_slice())
+        .map_err(|e| format!("Decompression failed: {}", e))?;
+    let mut files = archive::unpack(&tar)?;
+
+    let manifest: BundleManifest = files
+        .remove(MANIFEST_FILE)
+        .ok_or_else(|| "Bundle manifest is missing".to_string())
+        .and_then(|data| {
+            serde_json::from_slice(&data).map_err(|e| format!("Invalid manifest: {}", e))
+        })?;
+
+    if manifest.format != FORMAT {
+        return Err(format!("Unknown bundle format '{}'", manifest.format));
+    }
+    if manifest.schema_version > SCHEMA_VERSION {
+        return Err(format!(
+            "Bundle schema version {} is newer than supported version {}",
+            manifest.schema_version, SCHEMA_VERSION
+        ));
+    }
+
+    // Manifestte olmayan veya sağlaması tutmayan dosya kabul edilmez
+    for (name, data) in &files {
+        match manifest.checksums.get(name) {
+            Some(expected) if *expected == sha256_hex(data) => {}
+            Some(_) => return Err(format!("Checksum mismatch for {}", name)),
+            None => return Err(format!("Unexpected file in bundle: {}", name)),
+        }
+    }
+    if let Some(missing) = manifest
+        .checksums
+        .keys()
+        .find(|name| !files.contains_key(*name))
+    {
+        return Err(format!("File missing from bundle: {}", missing));
+    }
+
+    let bundle_items: Vec<BundleItem> = files
+        .remove(ITEMS_FILE)
+        .ok_or_else(|| "Bundle items are missing".to_string())
+        .and_then(|data| {
+            serde_json::from_slice(&data).map_err(|e| format!("Invalid items: {}", e))
+        })?;
+    if bundle_items.len() != manifest.item_count {
+        return Err(format!(
+            "Bundle lists {} items but contains {}",
+            manifest.item_count,
+            bundle_items.len()
+        ));
+    }
+
+    let items = bundle_items
+        .into_iter()
+        .map(|item| {
+            let image_data = match &item.image {
+                Some(name) => Some(
+                    files
+                        .get(name)
+                        .map(|png| general_purpose::STANDARD.encode(png))
+                        .ok_or_else(|| format!("File missing from bundle: {}", name))?,
+                ),
+                None => None,
+            };
+
+            Ok(ClipboardItem {
+                id: 0,
+                content: item.content,
+                content_type: item.content_type,
+                category: item.category,
+                image_data,
+                created_at: item.created_at,
+                pinned: item.pinned,
+                sensitive: item.sensitive,
+                source_app: item.source_app,
+                tags: item.tags,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok((manifest, items))
+}
@@ -0,0 +1,129 @@
+use crate::database;
+use crate::models::CaptureStatus;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::Emitter;
+
+// Duraklatma durumu veri klasöründe tutulur; böylece panoyu başka bir süreç (daemon) izlerken de geçerlidir
+const PAUSE_FILE: &str = "capture-pause.json";
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+enum Pause {
+    Indefinite,
+    Until(u64), // Unix zamanı (saniye)
+}
+
+fn pause_path() -> PathBuf {
+    database::data_dir().join(PAUSE_FILE)
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Kayıtlı duraklatmayı oku; süresi dolmuşsa dosya silinir
+fn current() -> Option<Pause> {
+    let pause = fs::read_to_string(pause_path())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())?;
+    match pause {
+        Pause::Until(until) if now() >= until => {
+            fs::remove_file(pause_path()).ok();
+            None
+        }
+        pause => Some(pause),
+    }
+}
+
+/// Kaydı duraklat; `duration` None ise `resume` çağrılana kadar
+pub fn pause(duration: Option<Duration>) -> Result<(), String> {
+    let pause = match duration {
+        Some(d) => Pause::Until(now().saturating_add(d.as_secs())),
+        None => Pause::Indefinite,
+    };
+    let json = serde_json::to_string(&pause).map_err(|e| format!("Serialize error: {}", e))?;
+    // İzleyici yarım yazılmış dosya okumasın
+    let path = pause_path();
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to pause capture: {}", e))
+}
+
+pub fn resume() -> Result<(), String> {
+    match fs::remove_file(pause_path()) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(format!("Failed to resume capture: {}", e))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Duraklatılmış mı? İzleyici her turda okur, böylece diğer süreçlerin değişikliği de görülür
+pub fn is_paused() -> bool {
+    current().is_some()
+}
+
+pub fn status() -> CaptureStatus {
+    let pause = current();
+    CaptureStatus {
+        paused: pause.is_some(),
+        remaining_seconds: match pause {
+            Some(Pause::Until(until)) => Some(until.saturating_sub(now())),
+            _ => None,
+        },
+    }
+}
+
+/// Durum değişikliğini arayüze bildir ve tepsi simgesini güncelle
+pub fn notify_status_changed(app_handle: &tauri::AppHandle) {
+    let status = status();
+    crate::tray::update_capture_status(app_handle, status.paused);
+
+    if let Err(e) = app_handle.emit("capture-status", status) {
+        eprintln!("Failed to send capture status event: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pause_is_shared_through_the_data_dir() {
+        let _guard = database::location::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = std::env::temp_dir().join(format!("clipcrab-pause-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        database::configure_data_dir(&["--data-dir".into(), dir.display().to_string()]).unwrap();
+
+        assert!(!is_paused());
+        pause(Some(Duration::from_secs(60))).unwrap();
+        assert!(dir.join(PAUSE_FILE).exists());
+        let timed = status();
+        assert!(timed.paused);
+        assert!(matches!(timed.remaining_seconds, Some(59..=60)));
+
+        // Başka bir sürecin yazdığı dosya da okunur
+        fs::write(dir.join(PAUSE_FILE), r#""Indefinite""#).unwrap();
+        assert!(is_paused());
+        assert_eq!(status().remaining_seconds, None);
+
+        // Süresi dolan duraklatma temizlenir
+        pause(Some(Duration::ZERO)).unwrap();
+        assert!(!is_paused());
+        assert!(!dir.join(PAUSE_FILE).exists());
+
+        pause(None).unwrap();
+        resume().unwrap();
+        assert!(!is_paused());
+        resume().unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,84 @@
+use crate::models::{CaptureLimits, LargeItemPolicy, Settings};
+use crate::settings;
+use image::{imageops::FilterType, RgbaImage};
+use rusqlite::Connection;
+
+/// Metnin sınırlara göre nasıl kaydedileceği
+pub enum TextCapture<'a> {
+    Full(&'a str),
+    Truncated(String),
+    Compressed(&'a str),
+    Skipped,
+}
+
+/// Kayıtlı sınırları oku (yoksa varsayılanlar)
+pub fn load(conn: &Connection) -> CaptureLimits {
+    settings::load(conn).capture_limits
+}
+
+/// Sınırlar ayarların parçası olarak saklanır; kaydedilen ayarların tamamı döner
+pub fn save(conn: &Connection, limits: &CaptureLimits) -> Result<Settings, String> {
+    let mut settings = settings::load(conn);
+    settings.capture_limits = limits.clone();
+    settings::save(conn, &settings)?;
+    Ok(settings)
+}
+
+/// Atlanan/kırpılan kaydı günlüğe yaz
+pub fn log_capture(
+    conn: &Connection,
+    content_type: &str,
+    action: &str,
+    reason: &str,
+    size: usize,
+    source_app: Option<&str>,
+) {
+    let result = conn.execute(
+        "INSERT INTO capture_log (content_type, action, reason, size, source_app, created_at) VALUES (?1, ?2, ?3, ?4, ?5, datetime('now', 'localtime'))",
+        rusqlite::params![content_type, action, reason, size as i64, source_app],
+    );
+    if let Err(e) = result {
+        eprintln!("Failed to write capture log: {}", e);
+    }
+}
+
+pub fn check_text<'a>(text: &'a str, limits: &CaptureLimits) -> TextCapture<'a> {
+    if text.len() <= limits.max_text_bytes {
+        return TextCapture::Full(text);
+    }
+
+    match limits.text_policy {
+        LargeItemPolicy::Skip => TextCapture::Skipped,
+        LargeItemPolicy::Truncate => TextCapture::Truncated(truncate(text, limits.max_text_bytes)),
+        LargeItemPolicy::Compress => TextCapture::Compressed(text),
+    }
+}
+
+/// Metni karakter sınırına denk gelecek şekilde kırp ve sonuna işaret ekle
+fn truncate(text: &str, max_bytes: usize) -> String {
+    let mut end = max_bytes.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!(
+        "{}\n… [truncated: {} of {} bytes kept]",
+        &text[..end],
+        end,
+        text.len()
+    )
+}
+
+/// Resmi en-boy oranını koruyarak en fazla `max_pixels` piksele küçült
+pub fn downscale(image: RgbaImage, max_pixels: u64) -> RgbaImage {
+    let (width, height) = image.dimensions();
+    let pixels = width as u64 * height as u64;
+    if pixels <= max_pixels {
+        return image;
+    }
+
+    let scale = (max_pixels as f64 / pixels as f64).sqrt();
+    let new_width = ((width as f64 * scale) as u32).max(1);
+    let new_height = ((height as f64 * scale) as u32).max(1);
+    image::imageops::resize(&image, new_width, new_height, FilterType::Triangle)
+}
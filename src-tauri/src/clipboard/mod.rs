@@ -1,2 +1,6 @@
+pub mod capture;
+pub mod limits;
+pub mod source;
 pub mod watcher;
+pub mod writer;
 pub use watcher::start_clipboard_watcher;
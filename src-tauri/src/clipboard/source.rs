@@ -0,0 +1,170 @@
+/// Panodaki içeriğin sahibi olan uygulama
+#[derive(Debug, Clone, Default)]
+pub struct SourceApp {
+    pub class: Option<String>,    // WM_CLASS sınıf adı, örn. "KeePassXC"
+    pub instance: Option<String>, // WM_CLASS örnek adı, örn. "keepassxc"
+    pub process: Option<String>,  // /proc/<pid>/comm
+    pub pid: Option<u32>,
+}
+
+impl SourceApp {
+    /// Veritabanında saklanacak ad
+    pub fn display_name(&self) -> Option<String> {
+        self.class
+            .clone()
+            .or_else(|| self.process.clone())
+            .or_else(|| self.instance.clone())
+    }
+
+    /// Yok sayma listesiyle karşılaştırılacak tüm adlar
+    pub fn names(&self) -> Vec<&str> {
+        [&self.class, &self.instance, &self.process]
+            .into_iter()
+            .filter_map(|name| name.as_deref())
+            .collect()
+    }
+}
+
+/// Panonun sahibini çözen yardımcı (watcher thread'inde yaşar)
+#[derive(Default)]
+pub struct SourceResolver {
+    #[cfg(target_os = "linux")]
+    x11: Option<x11::X11Source>,
+    #[cfg(target_os = "linux")]
+    x11_failed: bool,
+}
+
+impl SourceResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Panonun şu anki sahibini bul; desteklenmeyen platformlarda None
+    #[cfg(target_os = "linux")]
+    pub fn current_owner(&mut self) -> Option<SourceApp> {
+        if self.x11.is_none() && !self.x11_failed {
+            match x11::X11Source::connect() {
+                Some(source) => self.x11 = Some(source),
+                // Wayland'da (XWayland yoksa) tekrar denemeye gerek yok
+                None => self.x11_failed = true,
+            }
+        }
+
+        let source = self.x11.as_ref()?;
+        match source.owner() {
+            Ok(owner) => owner,
+            Err(_) => {
+                // Bağlantı koptu, bir sonraki sefer yeniden bağlan
+                self.x11 = None;
+                None
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub fn current_owner(&mut self) -> Option<SourceApp> {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod x11 {
+    use super::SourceApp;
+    use x11rb::protocol::xproto::{Atom, AtomEnum, ConnectionExt, Window};
+    use x11rb::rust_connection::RustConnection;
+
+    type X11Result<T> = Result<T, Box<dyn std::error::Error>>;
+
+    // Sahip pencerede WM_CLASS yoksa en fazla bu kadar üst pencereye bakılır
+    const MAX_PARENT_DEPTH: usize = 5;
+
+    pub struct X11Source {
+        conn: RustConnection,
+        clipboard: Atom,
+        net_wm_pid: Atom,
+    }
+
+    impl X11Source {
+        pub fn connect() -> Option<Self> {
+            let (conn, _) = RustConnection::connect(None).ok()?;
+            let clipboard = conn
+                .intern_atom(false, b"CLIPBOARD")
+                .ok()?
+                .reply()
+                .ok()?
+                .atom;
+            let net_wm_pid = conn
+                .intern_atom(false, b"_NET_WM_PID")
+                .ok()?
+                .reply()
+                .ok()?
+                .atom;
+
+            Some(Self {
+                conn,
+                clipboard,
+                net_wm_pid,
+            })
+        }
+
+        /// CLIPBOARD seçiminin sahibini WM_CLASS ve PID'e çevir
+        pub fn owner(&self) -> X11Result<Option<SourceApp>> {
+            let owner = self
+                .conn
+                .get_selection_owner(self.clipboard)?
+                .reply()?
+                .owner;
+            if owner == x11rb::NONE {
+                return Ok(None);
+            }
+
+            // Seçim sahibi çoğu zaman gizli bir yardımcı penceredir; üst pencerelere de bak
+            let mut window = owner;
+            for _ in 0..MAX_PARENT_DEPTH {
+                let app = self.window_info(window)?;
+                if app.class.is_some() || app.pid.is_some() {
+                    return Ok(Some(app));
+                }
+
+                let tree = self.conn.query_tree(window)?.reply()?;
+                if tree.parent == x11rb::NONE || tree.parent == tree.root {
+                    break;
+                }
+                window = tree.parent;
+            }
+
+            Ok(None)
+        }
+
+        fn window_info(&self, window: Window) -> X11Result<SourceApp> {
+            let mut app = SourceApp::default();
+
+            let wm_class = self
+                .conn
+                .get_property(false, window, AtomEnum::WM_CLASS, AtomEnum::STRING, 0, 1024)?
+                .reply()?;
+            // Biçim: "instance\0class\0"
+            let mut parts = wm_class
+                .value
+                .split(|b| *b == 0)
+                .filter(|part| !part.is_empty())
+                .map(|part| String::from_utf8_lossy(part).into_owned());
+            app.instance = parts.next();
+            app.class = parts.next();
+
+            let pid = self
+                .conn
+                .get_property(false, window, self.net_wm_pid, AtomEnum::CARDINAL, 0, 1)?
+                .reply()?;
+            app.pid = pid.value32().and_then(|mut values| values.next());
+            app.process = app.pid.and_then(|pid| {
+                std::fs::read_to_string(format!("/proc/{}/comm", pid))
+                    .ok()
+                    .map(|comm| comm.trim().to_string())
+                    .filter(|comm| !comm.is_empty())
+            });
+
+            Ok(app)
+        }
+    }
+}
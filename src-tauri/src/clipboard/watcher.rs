@@ -1,9 +1,13 @@
-use crate::database;
+use super::limits::{self, TextCapture};
+use super::source::{SourceApp, SourceResolver};
+use super::{capture, writer};
+use crate::database::{self, Pool};
 use crate::models::ClipboardUpdateEvent;
 use crate::security;
 use arboard::Clipboard;
 use base64::{engine::general_purpose, Engine as _};
 use image::ImageBuffer;
+use std::borrow::Cow;
 use std::{thread, time::Duration};
 use tauri::Emitter;
 
@@ -54,10 +58,97 @@ fn detect_category(content: &str) -> &'static str {
     "text"
 }
 
-pub fn start_clipboard_watcher(app_handle: tauri::AppHandle) {
+/// Kaynak uygulama yok sayma listesinde mi?
+fn is_ignored_app(conn: &rusqlite::Connection, source: Option<&SourceApp>) -> bool {
+    let Some(source) = source else {
+        return false;
+    };
+
+    source.names().into_iter().any(|name| {
+        conn.prepare_cached("SELECT EXISTS(SELECT 1 FROM ignored_apps WHERE name = ?1)")
+            .and_then(|mut stmt| stmt.query_row([name], |row| row.get::<_, bool>(0)))
+            .unwrap_or(false)
+    })
+}
+
+/// Güncel saklama ayarlarını uygula; ayarlar her kayıtta okunduğundan değişiklikler hemen geçerli olur
+fn enforce_retention(conn: &rusqlite::Connection) {
+    if let Err(e) = database::apply_retention(conn, &crate::settings::load(conn)) {
+        eprintln!("{}", e);
+    }
+}
+
+/// Saklama kurallarını yazma sırasında uygula
+fn write_retention(pool: &Pool) {
+    if let Err(e) = pool.write(|conn| {
+        enforce_retention(conn);
+        Ok(())
+    }) {
+        eprintln!("{}", e);
+    }
+}
+
+/// Yeni öğeyi yazma sırasında ekle ve saklama kurallarını aynı işlemde uygula.
+/// Hata, öğenin kaybolduğunu fark etmek için çağırana döner.
+fn insert_item(pool: &Pool, sql: &str, params: impl rusqlite::Params) -> Result<(), String> {
+    pool.write(|conn| {
+        conn.prepare_cached(sql)
+            .and_then(|mut stmt| stmt.execute(params))
+            .map_err(|e| format!("Failed to save clipboard item: {}", e))?;
+        enforce_retention(conn);
+        Ok(())
+    })
+}
+
+/// Atlanan veya kırpılan kaydı yazma sırasında günlüğe ekle
+fn log_capture(
+    pool: &Pool,
+    content_type: &str,
+    action: &str,
+    reason: &str,
+    size: usize,
+    source_app: Option<&str>,
+) {
+    let result = pool.write(|conn| {
+        limits::log_capture(conn, content_type, action, reason, size, source_app);
+        Ok(())
+    });
+    if let Err(e) = result {
+        eprintln!("{}", e);
+    }
+}
+
+/// Yeni öğeyi soket abonelerine ve (varsa) arayüze bildir
+fn notify_new_item(app_handle: Option<&tauri::AppHandle>, message: &str) {
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: message.to_string(),
+    };
+    crate::ipc::broadcast(&event);
+
+    let Some(app_handle) = app_handle else {
+        return;
+    };
+
+    println!("Event sending: {:?}", event);
+    match app_handle.emit("clipboard-update", event) {
+        Ok(_) => println!("Event sent successfully"),
+        Err(e) => eprintln!("Failed to send event: {}", e),
+    }
+
+    crate::tray::refresh_menu(app_handle);
+}
+
+/// Pano izleyicisini başlat; `app_handle` yoksa (daemon) yalnızca kayıt yapılır.
+/// İzleyici panoya erişemezse iş parçacığı sonlanır.
+pub fn start_clipboard_watcher(
+    pool: Pool,
+    app_handle: Option<tauri::AppHandle>,
+) -> thread::JoinHandle<()> {
     thread::spawn(move || {
-        // Watcher için optimize edilmiş bağlantı kullan
-        let conn = database::init_db_for_watcher();
+        let mut conn_dir = database::profile_dir();
+        // Uygulama kapalıyken süresi dolan öğeler
+        write_retention(&pool);
 
         // Linux'ta clipboard erişimi başarısız olabilir (X11/Wayland)
         // Retry mekanizması ile dene
@@ -98,9 +189,42 @@ pub fn start_clipboard_watcher(app_handle: tauri::AppHandle) {
         let mut last_clip_text = clipboard.get_text().unwrap_or_default();
         let mut last_clip_image = clipboard.get_image().ok();
         let mut consecutive_errors = 0;
+        let mut was_paused = false;
+        let mut source_resolver = SourceResolver::new();
         const MAX_CONSECUTIVE_ERRORS: u32 = 10;
 
         loop {
+            // Profil başka bir süreçte (arayüz, CLI) değiştiyse bu süreçteki görünümü de yenile
+            if crate::profiles::refresh() {
+                if let Some(app_handle) = &app_handle {
+                    crate::profiles::notify_switched(app_handle);
+                }
+            }
+            // Yeni kayıtlar yalnızca etkin profilin veritabanına yazılır (havuz profili izler)
+            let dir = database::profile_dir();
+            if dir != conn_dir {
+                conn_dir = dir;
+                write_retention(&pool);
+            }
+
+            // Duraklatılmışken veya etkin profil hiç kaydetmiyorken panoyu okumadan bekle
+            if capture::is_paused() || crate::profiles::never_capture() {
+                was_paused = true;
+                thread::sleep(Duration::from_millis(300));
+                continue;
+            }
+
+            // Devam edildiğinde duraklatma sırasında kopyalananları geç kaydetmemek için senkronize et
+            if was_paused {
+                was_paused = false;
+                last_clip_text = clipboard.get_text().unwrap_or_default();
+                last_clip_image = clipboard.get_image().ok();
+                if let Some(app_handle) = &app_handle {
+                    capture::notify_status_changed(app_handle);
+                }
+                continue;
+            }
+
             // Çok fazla ardışık hata varsa yavaşla
             if consecutive_errors >= MAX_CONSECUTIVE_ERRORS {
                 thread::sleep(Duration::from_secs(5));
@@ -112,38 +236,87 @@ pub fn start_clipboard_watcher(app_handle: tauri::AppHandle) {
             match clipboard.get_text() {
                 Ok(current) => {
                     consecutive_errors = 0;
-                    if current != last_clip_text && !current.trim().is_empty() {
-                        println!("New text content: {}", current);
-
-                        // Kategoriyi belirle (kayıt anında)
-                        let category = detect_category(&current);
-
-                        // İçeriği şifrele
-                        let encrypted_content = match security::encrypt(&current) {
-                            Ok(encrypted) => encrypted,
-                            Err(e) => {
-                                eprintln!("Failed to encrypt content: {}", e);
-                                current.clone()
-                            }
-                        };
-
-                        // Veritabanına ekle (kategori ile birlikte)
-                        let result = conn.execute(
-                            "INSERT INTO clipboard_history (content, content_type, category, created_at, is_encrypted) VALUES (?1, 'text', ?2, datetime('now', 'localtime'), 1)",
-                            [&encrypted_content, &category.to_string()],
-                        );
-
-                        if result.is_ok() {
-                            // Frontend'e yeni öğe eventi gönder
-                            let event = ClipboardUpdateEvent {
-                                action: "refresh".to_string(),
-                                message: "New text clipboard item added".to_string(),
+                    // Uygulamanın kendi yazdığı içerik (tepsi, snippet vb.) tekrar kaydedilmez
+                    if current != last_clip_text && writer::is_self_written_text(&current) {
+                        last_clip_text = current;
+                    } else if current != last_clip_text && !current.trim().is_empty() {
+                        let source = source_resolver.current_owner();
+
+                        let source_name = source.as_ref().and_then(SourceApp::display_name);
+                        let conn = pool.get();
+
+                        if is_ignored_app(&conn, source.as_ref()) {
+                            println!("Skipping text copied from ignored app");
+                        } else {
+                            // Boyut sınırına göre kırp, sıkıştır veya atla
+                            let limits = limits::load(&conn);
+                            let (text, compress) = match limits::check_text(&current, &limits) {
+                                TextCapture::Full(text) => (Some(Cow::Borrowed(text)), false),
+                                TextCapture::Compressed(text) => (Some(Cow::Borrowed(text)), true),
+                                TextCapture::Truncated(text) => {
+                                    log_capture(
+                                        &pool,
+                                        "text",
+                                        "truncated",
+                                        &format!(
+                                            "Text larger than {} bytes was truncated",
+                                            limits.max_text_bytes
+                                        ),
+                                        current.len(),
+                                        source_name.as_deref(),
+                                    );
+                                    (Some(Cow::Owned(text)), false)
+                                }
+                                TextCapture::Skipped => {
+                                    log_capture(
+                                        &pool,
+                                        "text",
+                                        "skipped",
+                                        &format!(
+                                            "Text larger than {} bytes",
+                                            limits.max_text_bytes
+                                        ),
+                                        current.len(),
+                                        source_name.as_deref(),
+                                    );
+                                    (None, false)
+                                }
                             };
 
-                            println!("Event sending: {:?}", event);
-                            match app_handle.emit("clipboard-update", event) {
-                                Ok(_) => println!("Event sent successfully"),
-                                Err(e) => eprintln!("Failed to send event: {}", e),
+                            if let Some(text) = text {
+                                println!("New text content: {} bytes", text.len());
+
+                                // Kategoriyi belirle (kayıt anında)
+                                let category = detect_category(&text);
+
+                                // İçeriği şifrele
+                                let encrypted = if compress {
+                                    security::encrypt_compressed(&text)
+                                } else {
+                                    security::encrypt(&text)
+                                };
+                                let encrypted_content = match encrypted {
+                                    Ok(encrypted) => encrypted,
+                                    Err(e) => {
+                                        eprintln!("Failed to encrypt content: {}", e);
+                                        text.to_string()
+                                    }
+                                };
+
+                                // Veritabanına ekle (kategori ile birlikte)
+                                let result = insert_item(
+                                    &pool,
+                                    "INSERT INTO clipboard_history (content, content_type, category, created_at, is_encrypted, source_app) VALUES (?1, 'text', ?2, datetime('now', 'localtime'), 1, ?3)",
+                                    rusqlite::params![&encrypted_content, category, source_name],
+                                );
+
+                                match result {
+                                    Ok(()) => notify_new_item(
+                                        app_handle.as_ref(),
+                                        "New text clipboard item added",
+                                    ),
+                                    Err(e) => eprintln!("{}", e),
+                                }
                             }
                         }
 
@@ -167,86 +340,120 @@ pub fn start_clipboard_watcher(app_handle: tauri::AppHandle) {
                     None => true,
                 };
 
-                if image_changed {
-                    println!("New image: {}x{}", image.width, image.height);
-
-                    let mut bytes = image.bytes.to_vec();
-
-                    // Alpha kanalı analizi
-                    let mut all_alpha_zero = true; // Tüm alpha değerleri 0 mı?
-                    let mut all_alpha_same = true; // Tüm alpha değerleri aynı mı?
-                    let first_alpha = bytes.get(3).copied().unwrap_or(255);
-
-                    for chunk in bytes.chunks(4) {
-                        if chunk.len() == 4 {
-                            if chunk[3] != 0 {
-                                all_alpha_zero = false;
-                            }
-                            if chunk[3] != first_alpha {
-                                all_alpha_same = false;
+                if image_changed && writer::is_self_written_image(&image) {
+                    last_clip_image = Some(image);
+                } else if image_changed {
+                    let source = source_resolver.current_owner();
+                    let conn = pool.get();
+
+                    if is_ignored_app(&conn, source.as_ref()) {
+                        println!("Skipping image copied from ignored app");
+                    } else {
+                        println!("New image: {}x{}", image.width, image.height);
+                        let source_name = source.as_ref().and_then(SourceApp::display_name);
+                        let limits = limits::load(&conn);
+
+                        let mut bytes = image.bytes.to_vec();
+
+                        // Alpha kanalı analizi
+                        let mut all_alpha_zero = true; // Tüm alpha değerleri 0 mı?
+                        let mut all_alpha_same = true; // Tüm alpha değerleri aynı mı?
+                        let first_alpha = bytes.get(3).copied().unwrap_or(255);
+
+                        for chunk in bytes.chunks(4) {
+                            if chunk.len() == 4 {
+                                if chunk[3] != 0 {
+                                    all_alpha_zero = false;
+                                }
+                                if chunk[3] != first_alpha {
+                                    all_alpha_same = false;
+                                }
                             }
                         }
-                    }
 
-                    // Sadece TÜM pikseller alpha=0 ve hepsi aynıysa düzelt
-                    // Bu, Minecraft gibi uygulamaların hatalı alpha kanalını düzeltir
-                    // Ama gerçek şeffaflık içeren resimlere dokunmaz
-                    if all_alpha_zero && all_alpha_same {
-                        println!("Fixing broken alpha channel (all pixels have alpha=0)");
-                        for chunk in bytes.chunks_exact_mut(4) {
-                            chunk[3] = 255;
+                        // Sadece TÜM pikseller alpha=0 ve hepsi aynıysa düzelt
+                        // Bu, Minecraft gibi uygulamaların hatalı alpha kanalını düzeltir
+                        // Ama gerçek şeffaflık içeren resimlere dokunmaz
+                        if all_alpha_zero && all_alpha_same {
+                            println!("Fixing broken alpha channel (all pixels have alpha=0)");
+                            for chunk in bytes.chunks_exact_mut(4) {
+                                chunk[3] = 255;
+                            }
                         }
-                    }
 
-                    let img_buffer = ImageBuffer::<image::Rgba<u8>, Vec<u8>>::from_raw(
-                        image.width as u32,
-                        image.height as u32,
-                        bytes,
-                    )
-                    .expect("Failed to create image buffer");
-
-                    let mut png_data = Vec::new();
-                    img_buffer
-                        .write_with_encoder(image::codecs::png::PngEncoder::new(&mut png_data))
-                        .expect("PNG encoding error");
-
-                    let base64_image = general_purpose::STANDARD.encode(&png_data);
-                    let content = format!("Image ({}x{})", image.width, image.height);
-
-                    // İçeriği ve resim verisini şifrele
-                    let encrypted_content = match security::encrypt(&content) {
-                        Ok(encrypted) => encrypted,
-                        Err(e) => {
-                            eprintln!("Failed to encrypt content: {}", e);
-                            content.clone()
+                        let img_buffer = ImageBuffer::<image::Rgba<u8>, Vec<u8>>::from_raw(
+                            image.width as u32,
+                            image.height as u32,
+                            bytes,
+                        )
+                        .expect("Failed to create image buffer");
+
+                        // Çok büyük resimleri piksel sınırına küçült
+                        let img_buffer = limits::downscale(img_buffer, limits.max_image_pixels);
+                        let (width, height) = img_buffer.dimensions();
+                        if (width, height) != (image.width as u32, image.height as u32) {
+                            log_capture(
+                                &pool,
+                                "image",
+                                "downscaled",
+                                &format!(
+                                    "Image {}x{} downscaled to {}x{}",
+                                    image.width, image.height, width, height
+                                ),
+                                image.bytes.len(),
+                                source_name.as_deref(),
+                            );
                         }
-                    };
 
-                    let encrypted_image = match security::encrypt(&base64_image) {
-                        Ok(encrypted) => encrypted,
-                        Err(e) => {
-                            eprintln!("Failed to encrypt image data: {}", e);
-                            base64_image.clone()
-                        }
-                    };
+                        let mut png_data = Vec::new();
+                        img_buffer
+                            .write_with_encoder(image::codecs::png::PngEncoder::new(&mut png_data))
+                            .expect("PNG encoding error");
+
+                        if png_data.len() > limits.max_image_bytes {
+                            log_capture(
+                                &pool,
+                                "image",
+                                "skipped",
+                                &format!("Image larger than {} bytes", limits.max_image_bytes),
+                                png_data.len(),
+                                source_name.as_deref(),
+                            );
+                        } else {
+                            let base64_image = general_purpose::STANDARD.encode(&png_data);
+                            let content = format!("Image ({}x{})", width, height);
+
+                            // İçeriği ve resim verisini şifrele
+                            let encrypted_content = match security::encrypt(&content) {
+                                Ok(encrypted) => encrypted,
+                                Err(e) => {
+                                    eprintln!("Failed to encrypt content: {}", e);
+                                    content.clone()
+                                }
+                            };
 
-                    // Veritabanına ekle (resim kategorisi ile)
-                    let result = conn.execute(
-                        "INSERT INTO clipboard_history (content, content_type, category, image_data, created_at, is_encrypted) VALUES (?1, 'image', 'image', ?2, datetime('now', 'localtime'), 1)",
-                        [&encrypted_content, &encrypted_image],
-                    );
+                            let encrypted_image = match security::encrypt(&base64_image) {
+                                Ok(encrypted) => encrypted,
+                                Err(e) => {
+                                    eprintln!("Failed to encrypt image data: {}", e);
+                                    base64_image.clone()
+                                }
+                            };
 
-                    if result.is_ok() {
-                        // Frontend'e yeni öğe eventi gönder
-                        let event = ClipboardUpdateEvent {
-                            action: "refresh".to_string(),
-                            message: "New image clipboard item added".to_string(),
-                        };
-
-                        println!("Event sending: {:?}", event);
-                        match app_handle.emit("clipboard-update", event) {
-                            Ok(_) => println!("Event sent successfully"),
-                            Err(e) => eprintln!("Failed to send event: {}", e),
+                            // Veritabanına ekle (resim kategorisi ile)
+                            let result = insert_item(
+                                &pool,
+                                "INSERT INTO clipboard_history (content, content_type, category, image_data, created_at, is_encrypted, source_app) VALUES (?1, 'image', 'image', ?2, datetime('now', 'localtime'), 1, ?3)",
+                                rusqlite::params![&encrypted_content, &encrypted_image, source_name],
+                            );
+
+                            match result {
+                                Ok(()) => notify_new_item(
+                                    app_handle.as_ref(),
+                                    "New image clipboard item added",
+                                ),
+                                Err(e) => eprintln!("{}", e),
+                            }
                         }
                     }
 
@@ -256,5 +463,5 @@ pub fn start_clipboard_watcher(app_handle: tauri::AppHandle) {
 
             thread::sleep(Duration::from_millis(300));
         }
-    });
+    })
 }
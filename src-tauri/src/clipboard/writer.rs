@@ -0,0 +1,125 @@
+use arboard::{Clipboard, ImageData};
+use lazy_static::lazy_static;
+use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+lazy_static! {
+    // Linux'ta Clipboard nesnesi düşürülünce içerik kaybolabilir, bu yüzden tek örnek tutulur
+    static ref WRITER: Mutex<Option<Clipboard>> = Mutex::new(None);
+    // Uygulamanın kendi yazdığı son içeriğin özeti; watcher bunu tekrar kaydetmez
+    static ref SELF_WRITTEN: Mutex<Option<u64>> = Mutex::new(None);
+}
+
+fn with_clipboard<T>(f: impl FnOnce(&mut Clipboard) -> Result<T, String>) -> Result<T, String> {
+    let mut guard = WRITER.lock().map_err(|e| e.to_string())?;
+    if guard.is_none() {
+        *guard = Some(Clipboard::new().map_err(|e| format!("Clipboard error: {}", e))?);
+    }
+    f(guard.as_mut().unwrap())
+}
+
+fn text_digest(text: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    ("text", text).hash(&mut hasher);
+    hasher.finish()
+}
+
+fn image_digest(width: usize, height: usize, bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    ("image", width, height, bytes).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Metni sistem panosuna yaz
+pub fn set_text(text: &str) -> Result<(), String> {
+    write_text(text, false)
+}
+
+/// PNG verisini çözüp resim olarak panoya yaz
+pub fn set_png(png_data: &[u8]) -> Result<(), String> {
+    write_png(png_data, false)
+}
+
+/// Kısa ömürlü süreçler (CLI) için: Linux'ta içerik yazan süreçle birlikte kaybolduğundan
+/// başka bir uygulama panoyu alana kadar bekler. Diğer sistemlerde hemen döner.
+pub fn set_text_and_wait(text: &str) -> Result<(), String> {
+    write_text(text, true)
+}
+
+pub fn set_png_and_wait(png_data: &[u8]) -> Result<(), String> {
+    write_png(png_data, true)
+}
+
+#[cfg(target_os = "linux")]
+fn set_data(cb: &mut Clipboard, data: ClipboardData, wait: bool) -> Result<(), arboard::Error> {
+    use arboard::SetExtLinux;
+
+    let set = if wait { cb.set().wait() } else { cb.set() };
+    match data {
+        ClipboardData::Text(text) => set.text(text),
+        ClipboardData::Image(image) => set.image(image),
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn set_data(cb: &mut Clipboard, data: ClipboardData, _wait: bool) -> Result<(), arboard::Error> {
+    match data {
+        ClipboardData::Text(text) => cb.set_text(text),
+        ClipboardData::Image(image) => cb.set_image(image),
+    }
+}
+
+enum ClipboardData {
+    Text(String),
+    Image(ImageData<'static>),
+}
+
+fn write_text(text: &str, wait: bool) -> Result<(), String> {
+    with_clipboard(|cb| {
+        set_data(cb, ClipboardData::Text(text.to_string()), wait)
+            .map_err(|e| format!("Failed to write clipboard: {}", e))
+    })?;
+    *SELF_WRITTEN.lock().unwrap() = Some(text_digest(text));
+    Ok(())
+}
+
+fn write_png(png_data: &[u8], wait: bool) -> Result<(), String> {
+    let rgba = image::load_from_memory(png_data)
+        .map_err(|e| format!("Failed to decode image: {}", e))?
+        .to_rgba8();
+    let (width, height) = (rgba.width() as usize, rgba.height() as usize);
+    let bytes = rgba.into_raw();
+    let digest = image_digest(width, height, &bytes);
+
+    with_clipboard(|cb| {
+        let image = ImageData {
+            width,
+            height,
+            bytes: Cow::Owned(bytes),
+        };
+        set_data(cb, ClipboardData::Image(image), wait)
+            .map_err(|e| format!("Failed to write clipboard: {}", e))
+    })?;
+    *SELF_WRITTEN.lock().unwrap() = Some(digest);
+    Ok(())
+}
+
+/// Panodaki mevcut metni oku
+pub fn get_text() -> Result<String, String> {
+    with_clipboard(|cb| {
+        cb.get_text()
+            .map_err(|e| format!("Failed to read clipboard: {}", e))
+    })
+}
+
+/// Bu metni uygulamanın kendisi mi yazdı?
+pub fn is_self_written_text(text: &str) -> bool {
+    *SELF_WRITTEN.lock().unwrap() == Some(text_digest(text))
+}
+
+/// Bu resmi uygulamanın kendisi mi yazdı?
+pub fn is_self_written_image(image: &ImageData) -> bool {
+    *SELF_WRITTEN.lock().unwrap() == Some(image_digest(image.width, image.height, &image.bytes))
+}
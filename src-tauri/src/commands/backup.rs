@@ -0,0 +1,76 @@
+use crate::backup;
+use crate::daemon;
+use crate::database::Pool;
+use crate::models::{BackupFile, BackupManifest, ClipboardUpdateEvent};
+use std::path::Path;
+use tauri::{Emitter, State};
+
+const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+fn non_empty(passphrase: Option<String>) -> Result<Option<String>, String> {
+    match passphrase.filter(|p| !p.is_empty()) {
+        Some(p) if p.chars().count() < MIN_PASSPHRASE_LENGTH => Err(format!(
+            "Passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LENGTH
+        )),
+        passphrase => Ok(passphrase),
+    }
+}
+
+/// Etkin profilin veritabanını ve anahtarını tek dosyaya yedekle; parola verilirse anahtar şifrelenir
+#[tauri::command]
+pub async fn create_backup(
+    pool: State<'_, Pool>,
+    path: String,
+    passphrase: Option<String>,
+) -> Result<BackupManifest, String> {
+    let passphrase = non_empty(passphrase)?;
+    let pool = pool.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        backup::create(&pool, Path::new(&path), passphrase.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Backup task failed: {}", e))?
+}
+
+/// Yedeği doğrulayıp etkin profilin geçmişinin, ayarlarının ve anahtarının yerine koy
+#[tauri::command]
+pub async fn restore_backup(
+    pool: State<'_, Pool>,
+    path: String,
+    passphrase: Option<String>,
+    app_handle: tauri::AppHandle,
+) -> Result<BackupManifest, String> {
+    // Daemon eski anahtarı önbellekte tutar ve yeni kayıtları onunla şifrelerdi
+    if daemon::is_attached() {
+        return Err("Stop the ClipCrab daemon before restoring a backup".to_string());
+    }
+
+    let manifest = tauri::async_runtime::spawn_blocking({
+        let pool = pool.inner().clone();
+        move || backup::restore(&pool, Path::new(&path), passphrase.as_deref())
+    })
+    .await
+    .map_err(|e| format!("Restore task failed: {}", e))??;
+
+    // Ayarlar da veritabanında olduğundan geri yüklenenler hemen uygulanır
+    let conn = pool.get();
+    crate::settings::notify_changed(&app_handle, &crate::settings::load(&conn));
+
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: format!("Restored {} items from backup", manifest.item_count),
+    };
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send restore event: {}", e);
+    }
+    crate::tray::refresh_menu(&app_handle);
+
+    Ok(manifest)
+}
+
+/// Zamanlanmış yedekler (en yeni önce)
+#[tauri::command]
+pub fn list_backups() -> Vec<BackupFile> {
+    backup::list()
+}
@@ -0,0 +1,66 @@
+use super::history::{import_items, load_decrypted_items};
+use crate::bundle;
+use crate::database::Pool;
+use crate::models::{BundleManifest, ClipboardUpdateEvent, ImportReport};
+use std::path::Path;
+use tauri::{Emitter, State};
+
+const MIN_PASSPHRASE_LENGTH: usize = 8;
+
+/// Geçmişi parolayla şifrelenmiş taşınabilir arşive aktar
+#[tauri::command]
+pub async fn export_bundle(
+    pool: State<'_, Pool>,
+    path: String,
+    passphrase: String,
+) -> Result<BundleManifest, String> {
+    if passphrase.chars().count() < MIN_PASSPHRASE_LENGTH {
+        return Err(format!(
+            "Passphrase must be at least {} characters",
+            MIN_PASSPHRASE_LENGTH
+        ));
+    }
+
+    // Anahtar türetme ve sıkıştırma UI'ı bloklamasın
+    let pool = pool.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let conn = pool.get();
+        let (items, skipped) = load_decrypted_items(&conn)?;
+        let created_at: String = conn
+            .query_row("SELECT datetime('now', 'localtime')", [], |row| row.get(0))
+            .map_err(|e| format!("Query failed: {}", e))?;
+
+        bundle::write(Path::new(&path), &passphrase, created_at, items, skipped)
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+/// Arşivi doğrula ve öğeleri yerel anahtarla şifreleyerek içe aktar
+#[tauri::command]
+pub async fn import_bundle(
+    pool: State<'_, Pool>,
+    path: String,
+    passphrase: String,
+    app_handle: tauri::AppHandle,
+) -> Result<ImportReport, String> {
+    let pool = pool.inner().clone();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        let (_, items) = bundle::read(Path::new(&path), &passphrase)?;
+        let mut conn = pool.get();
+        import_items(&pool, &mut conn, items, false)
+    })
+    .await
+    .map_err(|e| format!("Import task failed: {}", e))??;
+
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: format!("Imported {} items", report.imported),
+    };
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send import event: {}", e);
+    }
+    crate::tray::refresh_menu(&app_handle);
+
+    Ok(report)
+}
@@ -0,0 +1,26 @@
+use crate::clipboard::capture;
+use crate::models::CaptureStatus;
+use std::time::Duration;
+
+/// Kaydı duraklat; `duration_secs` verilmezse devam ettirilene kadar duraklatılır
+#[tauri::command]
+pub fn pause_capture(
+    duration_secs: Option<u64>,
+    app_handle: tauri::AppHandle,
+) -> Result<CaptureStatus, String> {
+    capture::pause(duration_secs.map(Duration::from_secs))?;
+    capture::notify_status_changed(&app_handle);
+    Ok(capture::status())
+}
+
+#[tauri::command]
+pub fn resume_capture(app_handle: tauri::AppHandle) -> Result<CaptureStatus, String> {
+    capture::resume()?;
+    capture::notify_status_changed(&app_handle);
+    Ok(capture::status())
+}
+
+#[tauri::command]
+pub fn capture_status() -> CaptureStatus {
+    capture::status()
+}
@@ -0,0 +1,64 @@
+use crate::clipboard::limits;
+use crate::database::Pool;
+use crate::models::{CaptureLimits, CaptureLogEntry};
+use crate::settings;
+use tauri::State;
+
+#[tauri::command]
+pub fn get_capture_limits(pool: State<'_, Pool>) -> CaptureLimits {
+    let conn = pool.get();
+    limits::load(&conn)
+}
+
+#[tauri::command]
+pub fn update_capture_limits(
+    pool: State<'_, Pool>,
+    limits: CaptureLimits,
+    app_handle: tauri::AppHandle,
+) -> Result<CaptureLimits, String> {
+    let settings = pool.write(|conn| limits::save(conn, &limits))?;
+    settings::notify_changed(&app_handle, &settings);
+    Ok(limits)
+}
+
+/// Boyut sınırları nedeniyle atlanan/kırpılan kayıtlar (en yeniler önce)
+#[tauri::command]
+pub fn list_capture_log(
+    pool: State<'_, Pool>,
+    limit: Option<i32>,
+) -> Result<Vec<CaptureLogEntry>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, content_type, action, reason, size, source_app, created_at
+             FROM capture_log ORDER BY id DESC LIMIT ?1",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let entries = stmt
+        .query_map([limit.unwrap_or(100)], |row| {
+            Ok(CaptureLogEntry {
+                id: row.get(0)?,
+                content_type: row.get(1)?,
+                action: row.get(2)?,
+                reason: row.get(3)?,
+                size: row.get(4)?,
+                source_app: row.get(5)?,
+                created_at: row.get(6)?,
+            })
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(entries)
+}
+
+#[tauri::command]
+pub fn clear_capture_log(pool: State<'_, Pool>) -> Result<(), String> {
+    pool.write(|conn| {
+        conn.execute("DELETE FROM capture_log", [])
+            .map_err(|e| format!("Failed to clear capture log: {}", e))?;
+        Ok(())
+    })
+}
@@ -1,13 +1,22 @@
-use crate::database;
+use crate::database::Pool;
 use crate::models::ClipboardUpdateEvent;
-use tauri::Emitter;
+use tauri::{Emitter, State};
 
+/// Tüm geçmişi çöp kutusuna taşı ve geri alma anahtarını döndür
 #[tauri::command]
-pub fn clear_all_history(app_handle: tauri::AppHandle) -> Result<(), String> {
-    let conn = database::init_db();
-
-    conn.execute("DELETE FROM clipboard_history", [])
-        .map_err(|e| format!("Clear error: {}", e))?;
+pub fn clear_all_history(
+    pool: State<'_, Pool>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    let undo_token = super::new_undo_token();
+    pool.write(|conn| {
+        conn.execute(
+            "UPDATE clipboard_history SET deleted_at = datetime('now', 'localtime'), undo_token = ?1
+             WHERE deleted_at IS NULL",
+            [&undo_token],
+        )
+        .map_err(|e| format!("Clear error: {}", e))
+    })?;
 
     // Frontend'e temizleme eventi gönder
     let event = ClipboardUpdateEvent {
@@ -21,5 +30,7 @@ pub fn clear_all_history(app_handle: tauri::AppHandle) -> Result<(), String> {
         Err(e) => eprintln!("Failed to send clear all event: {}", e),
     }
 
-    Ok(())
+    crate::tray::refresh_menu(&app_handle);
+
+    Ok(undo_token)
 }
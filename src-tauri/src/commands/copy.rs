@@ -0,0 +1,55 @@
+use crate::clipboard::writer;
+use crate::database::Pool;
+use crate::security;
+use base64::{engine::general_purpose, Engine as _};
+use rusqlite::OptionalExtension;
+use tauri::State;
+
+/// Geçmişteki öğeyi pencereyi açmadan tekrar panoya koy
+#[tauri::command]
+pub fn copy_clipboard_item(pool: State<'_, Pool>, id: i64) -> Result<(), String> {
+    copy_item(&pool, id, false)
+}
+
+/// Öğeyi panoya yaz; `wait` ise (CLI) başka bir uygulama panoyu alana kadar bekler
+pub fn copy_item(pool: &Pool, id: i64, wait: bool) -> Result<(), String> {
+    let conn = pool.get();
+
+    let (content, content_type, image_data, is_encrypted): (String, String, Option<String>, bool) =
+        conn.query_row(
+            "SELECT content, content_type, image_data, is_encrypted FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get item: {}", e))?
+        .ok_or_else(|| format!("Item {} not found", id))?;
+
+    if content_type == "image" {
+        let image_data = image_data.ok_or_else(|| "Image data missing".to_string())?;
+        let base64_image = if is_encrypted {
+            security::decrypt(&image_data)?
+        } else {
+            image_data
+        };
+        let png_data = general_purpose::STANDARD
+            .decode(base64_image)
+            .map_err(|e| format!("Invalid image data: {}", e))?;
+        return if wait {
+            writer::set_png_and_wait(&png_data)
+        } else {
+            writer::set_png(&png_data)
+        };
+    }
+
+    let text = if is_encrypted {
+        security::decrypt(&content)?
+    } else {
+        content
+    };
+    if wait {
+        writer::set_text_and_wait(&text)
+    } else {
+        writer::set_text(&text)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::daemon;
+use crate::database::{self, location, Pool};
+use crate::ipc;
+use crate::models::DataDirInfo;
+use std::path::Path;
+use tauri::State;
+
+#[tauri::command]
+pub fn get_data_directory() -> DataDirInfo {
+    database::data_dir_info()
+}
+
+/// Veritabanını ve anahtarı `target` klasörüne taşı ve uygulamayı yeni yerle yeniden başlat
+#[tauri::command]
+pub fn move_data_directory(
+    pool: State<'_, Pool>,
+    target: String,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    if daemon::is_attached() {
+        return Err("Stop the ClipCrab daemon before moving the data directory".to_string());
+    }
+
+    // Soket istemcileri taşıma sırasında eski klasöre yazmasın
+    let ipc_stopped = ipc::stop();
+    match location::move_data_dir(&pool, Path::new(&target)) {
+        // Eski klasörde yeni dosya oluşmadan yeni yerle başla
+        Ok(_) => app_handle.restart(),
+        Err(e) => {
+            if ipc_stopped {
+                ipc::start(pool.inner().clone(), Some(app_handle));
+            }
+            Err(e)
+        }
+    }
+}
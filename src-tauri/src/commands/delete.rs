@@ -1,13 +1,30 @@
-use crate::database;
+use crate::database::Pool;
 use crate::models::ClipboardUpdateEvent;
-use tauri::Emitter;
+use rusqlite::Connection;
+use tauri::{Emitter, State};
 
-#[tauri::command]
-pub fn delete_clipboard_item(id: i64, app_handle: tauri::AppHandle) -> Result<(), String> {
-    let conn = database::init_db();
-
-    conn.execute("DELETE FROM clipboard_history WHERE id = ?1", [id])
+/// Öğeyi çöp kutusuna taşı; öğe bulunamadıysa `false`.
+/// Aynı `undo_token` ile taşınanlar `undo_delete` ile birlikte geri alınır.
+pub fn delete_item(conn: &Connection, id: i64, undo_token: &str) -> Result<bool, String> {
+    let deleted = conn
+        .execute(
+            "UPDATE clipboard_history SET deleted_at = datetime('now', 'localtime'), undo_token = ?2
+             WHERE id = ?1 AND deleted_at IS NULL",
+            rusqlite::params![id, undo_token],
+        )
         .map_err(|e| format!("Delete error: {}", e))?;
+    Ok(deleted > 0)
+}
+
+/// Öğeyi çöp kutusuna taşı ve geri alma anahtarını döndür
+#[tauri::command]
+pub fn delete_clipboard_item(
+    pool: State<'_, Pool>,
+    id: i64,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    let undo_token = super::new_undo_token();
+    pool.write(|conn| delete_item(conn, id, &undo_token))?;
 
     // Frontend'e silme eventi gönder
     let event = ClipboardUpdateEvent {
@@ -21,5 +38,7 @@ pub fn delete_clipboard_item(id: i64, app_handle: tauri::AppHandle) -> Result<()
         Err(e) => eprintln!("Failed to send delete event: {}", e),
     }
 
-    Ok(())
+    crate::tray::refresh_menu(&app_handle);
+
+    Ok(undo_token)
 }
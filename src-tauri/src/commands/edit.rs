@@ -0,0 +1,141 @@
+use crate::database::{self, Pool};
+use crate::models::{ClipboardUpdateEvent, ItemRevision};
+use crate::security;
+use rusqlite::{params, Connection, OptionalExtension};
+use tauri::{Emitter, State};
+
+/// Öğenin mevcut (çözülmüş) içeriğini ve kategorisini al
+fn current_content(conn: &Connection, id: i64) -> Result<(String, String), String> {
+    let (content, content_type, category, is_encrypted): (String, String, String, bool) = conn
+        .query_row(
+            "SELECT content, content_type, COALESCE(category, 'text'), is_encrypted FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get item: {}", e))?
+        .ok_or_else(|| format!("Item {} not found", id))?;
+
+    if content_type == "image" {
+        return Err("Image items cannot be edited".to_string());
+    }
+
+    let content = if is_encrypted {
+        security::decrypt(&content)?
+    } else {
+        content
+    };
+
+    Ok((content, category))
+}
+
+/// Eski içeriği revizyon olarak sakla, yeni içeriği şifreleyip yaz (`Pool::write` içinde çağrılır)
+fn apply_update(conn: &Connection, id: i64, new_content: &str) -> Result<bool, String> {
+    if new_content.trim().is_empty() {
+        return Err("Content cannot be empty".to_string());
+    }
+
+    let (old_content, old_category) = current_content(conn, id)?;
+    if old_content == new_content {
+        return Ok(false);
+    }
+
+    conn.execute(
+        "INSERT INTO item_revisions (item_id, content, category, created_at) VALUES (?1, ?2, ?3, datetime('now', 'localtime'))",
+        params![id, security::encrypt(&old_content)?, old_category],
+    )
+    .map_err(|e| format!("Failed to save revision: {}", e))?;
+
+    let category = database::detect_category(new_content);
+    conn.execute(
+        "UPDATE clipboard_history SET content = ?1, category = ?2, is_encrypted = 1 WHERE id = ?3",
+        params![security::encrypt(new_content)?, category, id],
+    )
+    .map_err(|e| format!("Failed to update item: {}", e))?;
+
+    Ok(true)
+}
+
+fn emit_update(app_handle: &tauri::AppHandle, message: &str) {
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: message.to_string(),
+    };
+
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send update event: {}", e);
+    }
+
+    crate::tray::refresh_menu(app_handle);
+}
+
+#[tauri::command]
+pub fn update_clipboard_item(
+    pool: State<'_, Pool>,
+    id: i64,
+    new_content: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    if pool.write(|conn| apply_update(conn, id, &new_content))? {
+        emit_update(&app_handle, "Item updated");
+    }
+
+    Ok(())
+}
+
+/// Öğenin önceki sürümlerini en yeniden eskiye listele
+#[tauri::command]
+pub fn list_item_revisions(pool: State<'_, Pool>, id: i64) -> Result<Vec<ItemRevision>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, item_id, content, COALESCE(category, 'text'), created_at
+             FROM item_revisions WHERE item_id = ?1 ORDER BY id DESC",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let revisions = stmt
+        .query_map([id], |row| {
+            let content: String = row.get(2)?;
+            Ok(ItemRevision {
+                id: row.get(0)?,
+                item_id: row.get(1)?,
+                content: security::decrypt(&content).unwrap_or_default(),
+                category: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(revisions)
+}
+
+/// Öğeyi önceki bir sürüme döndür (mevcut içerik de revizyon olarak saklanır)
+#[tauri::command]
+pub fn revert_clipboard_item(
+    pool: State<'_, Pool>,
+    id: i64,
+    revision_id: i64,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let reverted = pool.write(|conn| {
+        let encrypted: String = conn
+            .query_row(
+                "SELECT content FROM item_revisions WHERE id = ?1 AND item_id = ?2",
+                [revision_id, id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| format!("Failed to get revision: {}", e))?
+            .ok_or_else(|| format!("Revision {} not found", revision_id))?;
+
+        apply_update(conn, id, &security::decrypt(&encrypted)?)
+    })?;
+    if reverted {
+        emit_update(&app_handle, "Item reverted");
+    }
+
+    Ok(())
+}
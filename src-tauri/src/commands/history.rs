@@ -1,43 +1,140 @@
-use crate::database;
-use crate::models::ClipboardItem;
+use crate::database::{self, Pool};
+use crate::export::{self, ExportFormat, ExportWriter, JsonWriter};
+use crate::models::{ClipboardItem, ExportFilter, ExportReport, ImportReport, RejectedItem};
 use crate::security;
+use base64::{engine::general_purpose, Engine as _};
 use rayon::prelude::*;
+use rusqlite::{Connection, OptionalExtension};
 use serde_json;
-use tauri::async_runtime;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use super::tags;
+use super::transfer::Transfer;
+use tauri::{async_runtime, State};
 
 /// Toplam öğe sayısını döndür (pagination için)
 #[tauri::command]
-pub fn get_clipboard_count() -> i64 {
-    let conn = database::init_db();
-    conn.query_row("SELECT COUNT(*) FROM clipboard_history", [], |row| {
-        row.get(0)
-    })
+pub fn get_clipboard_count(pool: State<'_, Pool>) -> i64 {
+    let conn = pool.get();
+    conn.query_row(
+        "SELECT COUNT(*) FROM clipboard_history WHERE deleted_at IS NULL",
+        [],
+        |row| row.get(0),
+    )
     .unwrap_or(0)
 }
 
-/// Tüm veritabanında arama yap - SQL ile kategori filtreleme (async)
+/// Tüm veritabanında arama yap - SQL ile kategori filtreleme (async).
+/// `tag_filter` virgülle ayrılmış etiketlerdir; yalnızca hepsini taşıyan öğeler döner.
 #[tauri::command]
 pub async fn search_clipboard_history(
+    pool: State<'_, Pool>,
     query: String,
     limit: Option<i32>,
     offset: Option<i32>,
     content_filter: Option<String>,
-) -> Vec<ClipboardItem> {
-    async_runtime::spawn_blocking(move || {
-        search_clipboard_history_sync(query, limit, offset, content_filter)
+    tag_filter: Option<String>,
+    source_app: Option<String>,
+) -> Result<Vec<ClipboardItem>, String> {
+    let pool = pool.inner().clone();
+    let items = async_runtime::spawn_blocking(move || {
+        search_clipboard_history_sync(
+            &pool,
+            query,
+            limit,
+            offset,
+            content_filter,
+            tag_filter,
+            source_app,
+        )
     })
     .await
-    .unwrap_or_else(|_| Vec::new())
+    .unwrap_or_else(|_| Vec::new());
+    Ok(items)
+}
+
+/// Kategori, etiket ve kaynak uygulama filtrelerinden WHERE koşulu oluştur; çöp kutusundakiler hariç tutulur.
+/// Tüm kullanıcı girdileri (kategoriler dahil) `params` sonuna eklenip numaralı parametre olarak bağlanır.
+fn filter_conditions(
+    filters: &[&str],
+    tag_filter: Option<&str>,
+    source_app: Option<&str>,
+    params: &mut Vec<rusqlite::types::Value>,
+) -> String {
+    let is_all_filter = filters.len() == 1 && (filters[0] == "all" || filters[0].is_empty());
+
+    let mut conditions = String::from(" AND deleted_at IS NULL");
+    if !is_all_filter {
+        let placeholders: Vec<String> = filters
+            .iter()
+            .map(|f| {
+                params.push(f.to_string().into());
+                format!("?{}", params.len())
+            })
+            .collect();
+        // NULL kategorileri text olarak kabul et
+        if filters.contains(&"text") {
+            conditions.push_str(&format!(
+                " AND (COALESCE(category, 'text') IN ({}))",
+                placeholders.join(",")
+            ));
+        } else {
+            conditions.push_str(&format!(" AND category IN ({})", placeholders.join(",")));
+        }
+    }
+
+    // Etiket filtresi: öğe verilen etiketlerin hepsini taşımalı (VE); adlar büyük/küçük harfe duyarsız
+    let mut seen = HashSet::new();
+    let tags: Vec<String> = tag_filter
+        .unwrap_or("")
+        .split(',')
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+        .collect();
+
+    if !tags.is_empty() {
+        let count = tags.len();
+        let placeholders: Vec<String> = tags
+            .into_iter()
+            .map(|tag| {
+                params.push(tag.into());
+                format!("?{}", params.len())
+            })
+            .collect();
+        conditions.push_str(&format!(
+            " AND id IN (SELECT it.item_id FROM item_tags it JOIN tags t ON t.id = it.tag_id WHERE t.name IN ({})
+               GROUP BY it.item_id HAVING COUNT(DISTINCT t.id) = {})",
+            placeholders.join(","),
+            count
+        ));
+    }
+
+    // Kaynak uygulama filtresi
+    if let Some(app) = source_app.map(str::trim).filter(|app| !app.is_empty()) {
+        params.push(app.to_string().into());
+        conditions.push_str(&format!(
+            " AND source_app = ?{} COLLATE NOCASE",
+            params.len()
+        ));
+    }
+
+    conditions
 }
 
 /// Senkron arama fonksiyonu - SQL seviyesinde filtreleme
-fn search_clipboard_history_sync(
+pub fn search_clipboard_history_sync(
+    pool: &Pool,
     query: String,
     limit: Option<i32>,
     offset: Option<i32>,
     content_filter: Option<String>,
+    tag_filter: Option<String>,
+    source_app: Option<String>,
 ) -> Vec<ClipboardItem> {
-    let conn = database::init_db();
+    let conn = pool.get();
 
     let limit_value = limit.unwrap_or(100);
     let offset_value = offset.unwrap_or(0);
@@ -45,32 +142,23 @@ fn search_clipboard_history_sync(
     // Filtreleri parse et
     let filter_str = content_filter.as_deref().unwrap_or("all");
     let filters: Vec<&str> = filter_str.split(',').map(|s| s.trim()).collect();
-    let is_all_filter = filters.len() == 1 && (filters[0] == "all" || filters[0].is_empty());
 
-    // SQL WHERE koşulunu oluştur
-    let category_condition = if is_all_filter {
-        String::new()
-    } else {
-        let placeholders: Vec<String> = filters.iter().map(|f| format!("'{}'", f)).collect();
-        // NULL kategorileri text olarak kabul et
-        if filters.contains(&"text") {
-            format!(
-                " AND (COALESCE(category, 'text') IN ({}))",
-                placeholders.join(",")
-            )
-        } else {
-            format!(" AND category IN ({})", placeholders.join(","))
-        }
-    };
+    let mut params: Vec<rusqlite::types::Value> = vec![limit_value.into(), offset_value.into()];
+    let conditions = filter_conditions(
+        &filters,
+        tag_filter.as_deref(),
+        source_app.as_deref(),
+        &mut params,
+    );
 
     // Query SQL - category ile filtreleme + pagination (SQL seviyesinde, çok hızlı)
     let sql = format!(
-        "SELECT id, content, content_type, COALESCE(category, 'text') as category, image_data, created_at, pinned, is_encrypted 
+        "SELECT id, content, content_type, COALESCE(category, 'text') as category, image_data, created_at, pinned, is_encrypted, sensitive, source_app 
          FROM clipboard_history 
          WHERE 1=1 {}
          ORDER BY pinned DESC, id DESC 
          LIMIT ?1 OFFSET ?2",
-        category_condition
+        conditions
     );
 
     let mut stmt = match conn.prepare(&sql) {
@@ -81,7 +169,7 @@ fn search_clipboard_history_sync(
         }
     };
 
-    let rows: Vec<_> = match stmt.query_map([limit_value, offset_value], |row| {
+    let rows: Vec<_> = match stmt.query_map(rusqlite::params_from_iter(params), |row| {
         Ok((
             row.get::<_, i64>(0)?,
             row.get::<_, String>(1)?,
@@ -92,6 +180,8 @@ fn search_clipboard_history_sync(
             row.get::<_, String>(5)?,
             row.get::<_, bool>(6)?,
             row.get::<_, bool>(7)?,
+            row.get::<_, bool>(8)?,
+            row.get::<_, Option<String>>(9)?,
         ))
     }) {
         Ok(mapped) => mapped.filter_map(Result::ok).collect(),
@@ -102,7 +192,8 @@ fn search_clipboard_history_sync(
     let is_image_only = filters.len() == 1 && filters[0] == "image";
 
     // Paralel şifre çözme - çok daha hızlı
-    rows.into_par_iter()
+    let mut items: Vec<ClipboardItem> = rows
+        .into_par_iter()
         .filter_map(
             |(
                 id,
@@ -113,6 +204,8 @@ fn search_clipboard_history_sync(
                 created_at,
                 pinned,
                 is_encrypted,
+                sensitive,
+                source_app,
             )| {
                 // Image-only filtrede content decrypt'e gerek yok (arama yoksa)
                 let decrypted_content = if is_image_only && query.is_empty() {
@@ -148,10 +241,16 @@ fn search_clipboard_history_sync(
                     image_data: decrypted_image,
                     created_at,
                     pinned,
+                    sensitive,
+                    source_app,
+                    tags: Vec::new(),
                 })
             },
         )
-        .collect()
+        .collect();
+
+    tags::attach_tags(&conn, &mut items);
+    items
 }
 
 /// category kolonu olmayan eski veritabanları için fallback
@@ -163,7 +262,7 @@ fn search_without_category(
     filters: &[&str],
 ) -> Vec<ClipboardItem> {
     let sql = "SELECT id, content, content_type, image_data, created_at, pinned, is_encrypted 
-               FROM clipboard_history ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
+               FROM clipboard_history WHERE deleted_at IS NULL ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
 
     let mut stmt = match conn.prepare(sql) {
         Ok(s) => s,
@@ -224,6 +323,9 @@ fn search_without_category(
                     image_data: decrypted_image,
                     created_at,
                     pinned,
+                    sensitive: false,
+                    source_app: None,
+                    tags: Vec::new(),
                 })
             },
         )
@@ -272,15 +374,26 @@ fn detect_category_from_content(content: &str, content_type: &str) -> String {
 
 /// Async wrapper - UI thread'i bloke etmez
 #[tauri::command]
-pub async fn get_clipboard_history(limit: Option<i32>, offset: Option<i32>) -> Vec<ClipboardItem> {
-    async_runtime::spawn_blocking(move || get_clipboard_history_sync(limit, offset))
-        .await
-        .unwrap_or_else(|_| Vec::new())
+pub async fn get_clipboard_history(
+    pool: State<'_, Pool>,
+    limit: Option<i32>,
+    offset: Option<i32>,
+) -> Result<Vec<ClipboardItem>, String> {
+    let pool = pool.inner().clone();
+    let items =
+        async_runtime::spawn_blocking(move || get_clipboard_history_sync(&pool, limit, offset))
+            .await
+            .unwrap_or_else(|_| Vec::new());
+    Ok(items)
 }
 
-/// Senkron versiyon
-fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<ClipboardItem> {
-    let conn = database::init_db();
+/// Senkron versiyon (CLI ve IPC de kullanır)
+pub fn get_clipboard_history_sync(
+    pool: &Pool,
+    limit: Option<i32>,
+    offset: Option<i32>,
+) -> Vec<ClipboardItem> {
+    let conn = pool.get();
 
     let limit_value = match limit {
         Some(l) if l <= 0 => 100000,
@@ -292,11 +405,11 @@ fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<Cl
 
     // category dahil yeni sorgu
     let sql_new =
-        "SELECT id, content, content_type, category, image_data, created_at, pinned, is_encrypted 
-                   FROM clipboard_history ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
+        "SELECT id, content, content_type, category, image_data, created_at, pinned, is_encrypted, sensitive, source_app 
+                   FROM clipboard_history WHERE deleted_at IS NULL ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
 
     let sql_old = "SELECT id, content, content_type, image_data, created_at, pinned, is_encrypted 
-                   FROM clipboard_history ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
+                   FROM clipboard_history WHERE deleted_at IS NULL ORDER BY pinned DESC, id DESC LIMIT ?1 OFFSET ?2";
 
     // Önce yeni formatı dene
     if let Ok(mut stmt) = conn.prepare(sql_new) {
@@ -310,13 +423,15 @@ fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<Cl
                 row.get::<_, String>(5)?,
                 row.get::<_, bool>(6)?,
                 row.get::<_, bool>(7)?,
+                row.get::<_, bool>(8)?,
+                row.get::<_, Option<String>>(9)?,
             ))
         }) {
             Ok(mapped) => mapped.filter_map(Result::ok).collect(),
             Err(_) => Vec::new(),
         };
 
-        return rows
+        let mut items: Vec<ClipboardItem> = rows
             .into_iter()
             .map(
                 |(
@@ -328,6 +443,8 @@ fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<Cl
                     created_at,
                     pinned,
                     is_encrypted,
+                    sensitive,
+                    source_app,
                 )| {
                     let decrypted_content = if is_encrypted {
                         security::decrypt(&content).unwrap_or(content)
@@ -349,10 +466,16 @@ fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<Cl
                         image_data: decrypted_image,
                         created_at,
                         pinned,
+                        sensitive,
+                        source_app,
+                        tags: Vec::new(),
                     }
                 },
             )
             .collect();
+
+        tags::attach_tags(&conn, &mut items);
+        return items;
     }
 
     // Eski format fallback
@@ -395,95 +518,601 @@ fn get_clipboard_history_sync(limit: Option<i32>, offset: Option<i32>) -> Vec<Cl
                 image_data: decrypted_image,
                 created_at,
                 pinned,
+                sensitive: false,
+                source_app: None,
+                tags: Vec::new(),
             }
         },
     )
     .collect()
 }
 
-#[tauri::command]
-pub fn export_clipboard_history() -> Result<String, String> {
-    let conn = database::init_db();
-    let sql = "SELECT id, content, content_type, category, image_data, created_at, pinned FROM clipboard_history ORDER BY id ASC";
-
-    let mut stmt = match conn.prepare(sql) {
-        Ok(stmt) => stmt,
-        Err(_) => {
-            // category yok, eski format
-            let sql_old = "SELECT id, content, content_type, image_data, created_at, pinned FROM clipboard_history ORDER BY id ASC";
-            let mut stmt = conn
-                .prepare(sql_old)
-                .map_err(|e| format!("Prepare failed: {}", e))?;
-
-            let items: Vec<ClipboardItem> = stmt
-                .query_map([], |row| {
-                    let content: String = row.get(1)?;
-                    let content_type: String = row.get(2)?;
-                    let category = detect_category_from_content(&content, &content_type);
-                    Ok(ClipboardItem {
-                        id: row.get(0)?,
-                        content,
-                        content_type,
-                        category,
-                        image_data: row.get(3)?,
-                        created_at: row.get(4)?,
-                        pinned: row.get(5)?,
-                    })
-                })
-                .map_err(|e| format!("Query failed: {}", e))?
-                .filter_map(Result::ok)
-                .collect();
-
-            return serde_json::to_string_pretty(&items).map_err(|e| format!("JSON failed: {}", e));
-        }
+/// Dışa aktarılacak satırı çöz; içerik veya resim açılamazsa None.
+/// Şifreli metin düz metinmiş gibi yazılmaz, resim de sessizce düşürülmez.
+fn decrypt_for_export(
+    content: String,
+    image_data: Option<String>,
+    is_encrypted: bool,
+) -> Option<(String, Option<String>)> {
+    if !is_encrypted {
+        return Some((content, image_data));
+    }
+    let content = security::decrypt(&content).ok()?;
+    let image_data = match image_data.filter(|data| !data.is_empty()) {
+        Some(data) => Some(security::decrypt(&data).ok()?),
+        None => None,
     };
+    Some((content, image_data))
+}
 
-    let items: Vec<ClipboardItem> = stmt
+/// Tüm öğeleri yerel anahtarla çözülmüş olarak yükle (dışa aktarma için, eskiden yeniye).
+/// Çözülemeyen öğeler atlanır; sayıları ikinci değerdir.
+pub(crate) fn load_decrypted_items(
+    conn: &Connection,
+) -> Result<(Vec<ClipboardItem>, usize), String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, content, content_type, category, image_data, created_at, pinned, is_encrypted, sensitive, source_app
+             FROM clipboard_history WHERE deleted_at IS NULL ORDER BY id ASC",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let rows: Vec<(ClipboardItem, bool)> = stmt
         .query_map([], |row| {
-            Ok(ClipboardItem {
-                id: row.get(0)?,
-                content: row.get(1)?,
-                content_type: row.get(2)?,
-                category: row.get(3)?,
-                image_data: row.get(4)?,
-                created_at: row.get(5)?,
-                pinned: row.get(6)?,
-            })
+            Ok((
+                ClipboardItem {
+                    id: row.get(0)?,
+                    content: row.get(1)?,
+                    content_type: row.get(2)?,
+                    category: row.get(3)?,
+                    image_data: row.get(4)?,
+                    created_at: row.get(5)?,
+                    pinned: row.get(6)?,
+                    sensitive: row.get(8)?,
+                    source_app: row.get(9)?,
+                    tags: Vec::new(),
+                },
+                row.get(7)?,
+            ))
         })
         .map_err(|e| format!("Query failed: {}", e))?
         .filter_map(Result::ok)
         .collect();
 
-    serde_json::to_string_pretty(&items).map_err(|e| format!("JSON failed: {}", e))
+    let total = rows.len();
+    let mut items: Vec<ClipboardItem> = rows
+        .into_iter()
+        .filter_map(|(item, is_encrypted)| {
+            let (content, image_data) =
+                decrypt_for_export(item.content, item.image_data, is_encrypted)?;
+            Some(ClipboardItem {
+                content,
+                image_data,
+                ..item
+            })
+        })
+        .collect();
+    let skipped = total - items.len();
+
+    tags::attach_tags(conn, &mut items);
+    Ok((items, skipped))
 }
 
+/// Öğeyi yerel anahtarla şifreleyip etiketleriyle birlikte ekle
+pub(crate) fn insert_item(conn: &Connection, item: &ClipboardItem) -> Result<i64, String> {
+    let category = if item.category.is_empty() {
+        detect_category_from_content(&item.content, &item.content_type)
+    } else {
+        item.category.clone()
+    };
+    let content = security::encrypt(&item.content)?;
+    let image_data = item
+        .image_data
+        .as_deref()
+        .filter(|data| !data.is_empty())
+        .map(security::encrypt)
+        .transpose()?;
+
+    conn.execute(
+        "INSERT INTO clipboard_history (content, content_type, category, image_data, created_at, pinned, is_encrypted, sensitive, source_app) VALUES (?1, ?2, ?3, ?4, ?5, ?6, 1, ?7, ?8)",
+        rusqlite::params![
+            content,
+            &item.content_type,
+            category,
+            image_data,
+            &item.created_at,
+            item.pinned,
+            item.sensitive,
+            &item.source_app,
+        ],
+    )
+    .map_err(|e| format!("Failed to insert item: {}", e))?;
+
+    let item_id = conn.last_insert_rowid();
+    for tag in &item.tags {
+        tags::tag_item(conn, item_id, tag)?;
+    }
+    Ok(item_id)
+}
+
+/// Tek öğeyi çözülmüş içeriği ve etiketleriyle getir
+pub fn get_item(conn: &Connection, id: i64) -> Result<Option<ClipboardItem>, String> {
+    let row = conn
+        .query_row(
+            "SELECT id, content, content_type, COALESCE(category, 'text'), image_data, created_at, pinned, is_encrypted, sensitive, source_app
+             FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+            [id],
+            |row| {
+                Ok((
+                    ClipboardItem {
+                        id: row.get(0)?,
+                        content: row.get(1)?,
+                        content_type: row.get(2)?,
+                        category: row.get(3)?,
+                        image_data: row.get(4)?,
+                        created_at: row.get(5)?,
+                        pinned: row.get(6)?,
+                        sensitive: row.get(8)?,
+                        source_app: row.get(9)?,
+                        tags: Vec::new(),
+                    },
+                    row.get::<_, bool>(7)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get item: {}", e))?;
+
+    let Some((mut item, is_encrypted)) = row else {
+        return Ok(None);
+    };
+    if is_encrypted {
+        item.content = security::decrypt(&item.content)?;
+        item.image_data = item
+            .image_data
+            .map(|img| security::decrypt(&img))
+            .transpose()?;
+    }
+    tags::attach_tags(conn, std::slice::from_mut(&mut item));
+    Ok(Some(item))
+}
+
+/// Panodan gelmeyen metni (CLI, IPC) geçmişe ekle
+pub fn add_text_item(
+    conn: &Connection,
+    text: &str,
+    source_app: Option<&str>,
+) -> Result<i64, String> {
+    if text.trim().is_empty() {
+        return Err("Cannot add empty text".to_string());
+    }
+    let created_at: String = conn
+        .query_row("SELECT datetime('now', 'localtime')", [], |row| row.get(0))
+        .map_err(|e| format!("Query failed: {}", e))?;
+
+    insert_item(
+        conn,
+        &ClipboardItem {
+            id: 0,
+            content: text.to_string(),
+            content_type: "text".to_string(),
+            category: String::new(),
+            image_data: None,
+            created_at,
+            pinned: false,
+            sensitive: false,
+            source_app: source_app.map(str::to_string),
+            tags: Vec::new(),
+        },
+    )
+}
+
+/// Filtreye uyan öğeleri sırayla çözüp yazıcıya aktar; belleğe toplu yüklenmez.
+/// Çözülemeyen öğeler yazılmaz, raporda sayılır.
+pub fn stream_items(
+    conn: &Connection,
+    filter: &ExportFilter,
+    writer: &mut dyn ExportWriter,
+    transfer: &mut Transfer,
+) -> Result<ExportReport, String> {
+    let filter_str = filter.content_filter.as_deref().unwrap_or("all");
+    let filters: Vec<&str> = filter_str.split(',').map(|s| s.trim()).collect();
+
+    let mut params: Vec<rusqlite::types::Value> = Vec::new();
+    let conditions = filter_conditions(
+        &filters,
+        filter.tag_filter.as_deref(),
+        filter.source_app.as_deref(),
+        &mut params,
+    );
+    let sql = format!(
+        "SELECT id, content, content_type, COALESCE(category, 'text'), image_data, created_at, pinned, is_encrypted, sensitive, source_app
+         FROM clipboard_history
+         WHERE 1=1 {}
+         ORDER BY id ASC",
+        conditions
+    );
+
+    // Metin araması satırlar çözüldükten sonra yapıldığından toplam, taranacak satır sayısıdır
+    let total: i64 = conn
+        .query_row(
+            &format!(
+                "SELECT COUNT(*) FROM clipboard_history WHERE 1=1 {}",
+                conditions
+            ),
+            rusqlite::params_from_iter(params.iter()),
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Query failed: {}", e))?;
+    transfer.set_total(total as u64);
+
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+    let mut rows = stmt
+        .query(rusqlite::params_from_iter(params))
+        .map_err(|e| format!("Query failed: {}", e))?;
+
+    let query_lower = filter.query.as_deref().unwrap_or("").trim().to_lowercase();
+    let mut report = ExportReport::default();
+    let mut scanned = 0;
+
+    while let Some(row) = rows.next().map_err(|e| format!("Query failed: {}", e))? {
+        scanned += 1;
+        transfer.progress(report.exported, scanned)?;
+
+        let is_encrypted: bool = row.get(7).map_err(|e| e.to_string())?;
+        let content: String = row.get(1).map_err(|e| e.to_string())?;
+        let image_data: Option<String> = row.get(4).map_err(|e| e.to_string())?;
+        let Some((content, image_data)) = decrypt_for_export(content, image_data, is_encrypted)
+        else {
+            report.skipped += 1;
+            continue;
+        };
+
+        // Arama eşleşmesi
+        if !query_lower.is_empty() && !content.to_lowercase().contains(&query_lower) {
+            continue;
+        }
+
+        let id: i64 = row.get(0).map_err(|e| e.to_string())?;
+        let item = ClipboardItem {
+            id,
+            content,
+            content_type: row.get(2).map_err(|e| e.to_string())?,
+            category: row.get(3).map_err(|e| e.to_string())?,
+            image_data,
+            created_at: row.get(5).map_err(|e| e.to_string())?,
+            pinned: row.get(6).map_err(|e| e.to_string())?,
+            sensitive: row.get(8).map_err(|e| e.to_string())?,
+            source_app: row.get(9).map_err(|e| e.to_string())?,
+            tags: tags::load_tags_for_items(conn, &[id])
+                .remove(&id)
+                .unwrap_or_default(),
+        };
+
+        writer.write_item(&item)?;
+        report.exported += 1;
+    }
+
+    writer.finish()?;
+    transfer.finish(report.exported, scanned);
+    Ok(report)
+}
+
+/// Geçmişi seçilen biçimde dışa aktar.
+/// `path` verilmezse yalnızca JSON desteklenir ve belge metin olarak döner;
+/// verilirse öğeler diske akıtılır, ilerleme "transfer-progress" ile bildirilir ve özet mesajı döner.
 #[tauri::command]
-pub fn import_clipboard_history(json_data: String) -> Result<usize, String> {
-    let conn = database::init_db();
-    let items: Vec<ClipboardItem> = serde_json::from_str(&json_data).map_err(|e| e.to_string())?;
-    let mut inserted = 0;
+pub async fn export_clipboard_history(
+    pool: State<'_, Pool>,
+    format: Option<String>,
+    path: Option<String>,
+    filter: Option<ExportFilter>,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    let format = ExportFormat::parse(format.as_deref().unwrap_or("json"))?;
+    let filter = filter.unwrap_or_default();
+    let pool = pool.inner().clone();
 
-    for item in items {
-        let category = if item.category.is_empty() {
-            detect_category_from_content(&item.content, &item.content_type)
+    async_runtime::spawn_blocking(move || {
+        let conn = pool.get();
+
+        let Some(path) = path else {
+            if format != ExportFormat::Json {
+                return Err("A destination path is required for this export format".to_string());
+            }
+            let mut writer = JsonWriter::new(Vec::new());
+            let report = stream_items(
+                &conn,
+                &filter,
+                &mut writer,
+                &mut Transfer::begin(None, "export"),
+            )?;
+            if report.skipped > 0 {
+                eprintln!(
+                    "Skipped {} items that could not be decrypted",
+                    report.skipped
+                );
+            }
+            return String::from_utf8(writer.into_inner()).map_err(|e| e.to_string());
+        };
+
+        let mut transfer = Transfer::begin(Some(app_handle), "export");
+        let result = export::create_writer(format, Path::new(&path))
+            .and_then(|mut writer| stream_items(&conn, &filter, writer.as_mut(), &mut transfer));
+
+        match result {
+            Ok(report) if report.skipped > 0 => Ok(format!(
+                "Exported {} items to {} (skipped {} items that could not be decrypted)",
+                report.exported, path, report.skipped
+            )),
+            Ok(report) => Ok(format!("Exported {} items to {}", report.exported, path)),
+            Err(e) => {
+                // İptal veya hata sonrası yarım kalan dosya bırakılmaz
+                if format != ExportFormat::Folder {
+                    fs::remove_file(&path).ok();
+                }
+                Err(e)
+            }
+        }
+    })
+    .await
+    .map_err(|e| format!("Export task failed: {}", e))?
+}
+
+/// İçerik özeti (aynı öğeyi tekrar içe aktarmamak için)
+fn content_hash(item: &ClipboardItem) -> [u8; 32] {
+    hash_parts(
+        &item.content_type,
+        &item.content,
+        item.image_data.as_deref(),
+    )
+}
+
+fn hash_parts(content_type: &str, content: &str, image_data: Option<&str>) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(content_type.as_bytes());
+    hasher.update([0]);
+    hasher.update(content.as_bytes());
+    hasher.update([0]);
+    hasher.update(image_data.unwrap_or_default().as_bytes());
+    hasher.finalize().into()
+}
+
+/// Yerel anahtarla şifrelenmiş eski dışa aktarımlar çözülür, diğerleri olduğu gibi kalır
+fn decrypt_if_local(value: String) -> String {
+    security::decrypt(&value).unwrap_or(value)
+}
+
+fn has_timezone(timestamp: &str) -> bool {
+    let timestamp = timestamp.trim();
+    if timestamp.ends_with(['Z', 'z']) {
+        return true;
+    }
+    // Saatten sonra gelen ±HH:MM; içe aktarılan metin ASCII olmayabilir, baytlarla karşılaştırılır
+    let bytes = timestamp.as_bytes();
+    bytes.len() > 16
+        && matches!(bytes[bytes.len() - 6], b'+' | b'-')
+        && bytes[bytes.len() - 3] == b':'
+}
+
+/// İçe aktarılacak öğeyi doğrula ve normalize et
+fn validate_import_item(
+    conn: &Connection,
+    mut item: ClipboardItem,
+) -> Result<ClipboardItem, String> {
+    item.content = decrypt_if_local(item.content);
+    item.image_data = item
+        .image_data
+        .filter(|data| !data.is_empty())
+        .map(decrypt_if_local);
+
+    match item.content_type.as_str() {
+        "text" => {
+            if item.content.trim().is_empty() {
+                return Err("Empty content".to_string());
+            }
+        }
+        "image" => {
+            let data = item
+                .image_data
+                .as_deref()
+                .ok_or_else(|| "Missing image data".to_string())?;
+            general_purpose::STANDARD
+                .decode(data)
+                .map_err(|_| "Invalid image data".to_string())?;
+        }
+        other => return Err(format!("Unknown content type '{}'", other)),
+    }
+
+    // SQLite geçersiz tarihlerde NULL döndürür; geçerliyse standart biçime çevrilir.
+    // Saat dilimi belirtilen zamanlar (…Z, +03:00) yerel saate dönüştürülür.
+    let sql = if has_timezone(&item.created_at) {
+        "SELECT datetime(?1, 'localtime')"
+    } else {
+        "SELECT datetime(?1)"
+    };
+    let normalized: Option<String> = conn
+        .query_row(sql, [&item.created_at], |row| row.get(0))
+        .map_err(|e| format!("Query failed: {}", e))?;
+    item.created_at =
+        normalized.ok_or_else(|| format!("Invalid timestamp '{}'", item.created_at))?;
+
+    Ok(item)
+}
+
+/// Mevcut öğelerin içerik özetleri; satırlar tek tek çözülür, geçmiş belleğe alınmaz
+fn existing_hashes(conn: &Connection) -> Result<HashSet<[u8; 32]>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT content, content_type, image_data, is_encrypted FROM clipboard_history
+             WHERE deleted_at IS NULL",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+    let mut rows = stmt.query([]).map_err(|e| format!("Query failed: {}", e))?;
+
+    let mut hashes = HashSet::new();
+    while let Some(row) = rows.next().map_err(|e| format!("Query failed: {}", e))? {
+        let content: String = row.get(0).map_err(|e| e.to_string())?;
+        let content_type: String = row.get(1).map_err(|e| e.to_string())?;
+        let image_data: Option<String> = row.get(2).map_err(|e| e.to_string())?;
+        let is_encrypted: bool = row.get(3).map_err(|e| e.to_string())?;
+
+        let (content, image_data) = if is_encrypted {
+            (
+                security::decrypt(&content).unwrap_or(content),
+                image_data.and_then(|img| security::decrypt(&img).ok()),
+            )
         } else {
-            item.category
+            (content, image_data)
+        };
+        hashes.insert(hash_parts(&content_type, &content, image_data.as_deref()));
+    }
+    Ok(hashes)
+}
+
+/// Tek `IMMEDIATE` işlem içinde öğe öğe içe aktarma; doğrulama, tekrar ayıklama ve rapor burada tutulur.
+/// Deneme modunda işlem onaylanmadan bırakılır ve geri alınır.
+pub(crate) struct ImportSession<'c> {
+    tx: database::WriteTransaction<'c>,
+    seen: HashSet<[u8; 32]>,
+    report: ImportReport,
+    index: usize,
+}
+
+impl<'c> ImportSession<'c> {
+    pub(crate) fn begin(
+        pool: &'c Pool,
+        conn: &'c mut Connection,
+        dry_run: bool,
+    ) -> Result<Self, String> {
+        // Mevcut öğeler yazma kilidi alındıktan sonra okunur; arada eklenenler de tekrar sayılır
+        let tx = pool.begin_write(conn)?;
+        let seen = existing_hashes(&tx)?;
+        Ok(Self {
+            tx,
+            seen,
+            report: ImportReport {
+                dry_run,
+                ..Default::default()
+            },
+            index: 0,
+        })
+    }
+
+    /// Okunan öğeyi ekle; çözümlenemeyen öğe reddedilenlere yazılır
+    pub(crate) fn add(&mut self, item: Result<ClipboardItem, String>) -> Result<(), String> {
+        let index = self.index;
+        self.index += 1;
+
+        let item = match item.and_then(|item| validate_import_item(&self.tx, item)) {
+            Ok(item) => item,
+            Err(reason) => {
+                self.report.rejected.push(RejectedItem { index, reason });
+                return Ok(());
+            }
         };
 
-        let result = conn.execute(
-            "INSERT INTO clipboard_history (content, content_type, category, image_data, created_at, pinned, is_encrypted) VALUES (?1, ?2, ?3, ?4, ?5, ?6, 0)",
-            rusqlite::params![
-                &item.content,
-                &item.content_type,
-                &category,
-                &item.image_data.unwrap_or_default(),
-                &item.created_at,
-                item.pinned,
-            ],
-        );
-        if result.is_ok() {
-            inserted += 1;
+        if !self.seen.insert(content_hash(&item)) {
+            self.report.duplicates += 1;
+            return Ok(());
+        }
+
+        insert_item(&self.tx, &item)?;
+        self.report.imported += 1;
+        Ok(())
+    }
+
+    /// Şimdiye kadar okunan öğe sayısı
+    pub(crate) fn processed(&self) -> usize {
+        self.index
+    }
+
+    pub(crate) fn finish(self) -> Result<ImportReport, String> {
+        if !self.report.dry_run {
+            self.tx.commit()?;
+        }
+        Ok(self.report)
+    }
+}
+
+/// Öğeleri tek işlemde doğrulayıp şifreleyerek ekle; tekrarlar atlanır.
+/// `dry_run` ise işlem geri alınır ve yalnızca rapor döner.
+pub(crate) fn import_items(
+    pool: &Pool,
+    conn: &mut Connection,
+    items: Vec<ClipboardItem>,
+    dry_run: bool,
+) -> Result<ImportReport, String> {
+    let mut session = ImportSession::begin(pool, conn, dry_run)?;
+    for item in items {
+        session.add(Ok(item))?;
+    }
+    session.finish()
+}
+
+#[tauri::command]
+pub fn import_clipboard_history(
+    pool: State<'_, Pool>,
+    json_data: String,
+) -> Result<ImportReport, String> {
+    let mut conn = pool.get();
+    let items: Vec<ClipboardItem> = serde_json::from_str(&json_data).map_err(|e| e.to_string())?;
+    import_items(&pool, &mut conn, items, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import_item(created_at: &str) -> ClipboardItem {
+        serde_json::from_value(serde_json::json!({
+            "id": 0,
+            "content": "hello",
+            "content_type": "text",
+            "category": "text",
+            "image_data": null,
+            "created_at": created_at,
+            "pinned": false,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn detects_timezone_suffix() {
+        assert!(has_timezone("2024-05-01T10:00:00Z"));
+        assert!(has_timezone("2024-05-01T10:00:00+03:00"));
+        assert!(has_timezone("2024-05-01 10:00:00-05:30 "));
+        assert!(!has_timezone("2024-05-01 10:00:00"));
+        assert!(!has_timezone("2024-05-01"));
+        assert!(!has_timezone("2024-05-01 10:0€0:00"));
+        assert!(!has_timezone("2024-05-01 10:00:00 öğleden"));
+        assert!(!has_timezone("ğğğğğğğğğ"));
+    }
+
+    #[test]
+    fn rejects_non_ascii_timestamps() {
+        let _guard = database::location::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = std::env::temp_dir().join(format!("clipcrab-import-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        database::configure_data_dir(&["--data-dir".into(), dir.display().to_string()]).unwrap();
+        let conn = Connection::open_in_memory().unwrap();
+
+        for created_at in [
+            "2024-05-01 10:0€0:00",
+            "2024-05-01T10:00:00+0ş:00",
+            "€€€€€€€€€",
+        ] {
+            let error = validate_import_item(&conn, import_item(created_at))
+                .err()
+                .unwrap();
+            assert_eq!(error, format!("Invalid timestamp '{}'", created_at));
         }
+        let item = validate_import_item(&conn, import_item("2024-05-01T10:00:00")).unwrap();
+        assert_eq!(item.created_at, "2024-05-01 10:00:00");
+        fs::remove_dir_all(&dir).ok();
     }
-    Ok(inserted)
 }
@@ -0,0 +1,67 @@
+use crate::database::Pool;
+use tauri::State;
+
+/// Kaydı yapılmayacak uygulamaları listele
+#[tauri::command]
+pub fn list_ignored_apps(pool: State<'_, Pool>) -> Result<Vec<String>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare("SELECT name FROM ignored_apps ORDER BY name COLLATE NOCASE")
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let apps = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(apps)
+}
+
+/// Uygulamayı yok sayma listesine ekle (WM_CLASS sınıfı/örneği veya süreç adı)
+#[tauri::command]
+pub fn add_ignored_app(pool: State<'_, Pool>, name: String) -> Result<(), String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Application name cannot be empty".to_string());
+    }
+
+    pool.write(|conn| {
+        conn.execute(
+            "INSERT OR IGNORE INTO ignored_apps (name, created_at) VALUES (?1, datetime('now', 'localtime'))",
+            [name],
+        )
+        .map_err(|e| format!("Failed to add ignored app: {}", e))?;
+        Ok(())
+    })
+}
+
+#[tauri::command]
+pub fn remove_ignored_app(pool: State<'_, Pool>, name: String) -> Result<(), String> {
+    pool.write(|conn| {
+        conn.execute("DELETE FROM ignored_apps WHERE name = ?1", [name.trim()])
+            .map_err(|e| format!("Failed to remove ignored app: {}", e))?;
+        Ok(())
+    })
+}
+
+/// Geçmişte görülen kaynak uygulamalar (arama filtresi için)
+#[tauri::command]
+pub fn list_source_apps(pool: State<'_, Pool>) -> Result<Vec<String>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT DISTINCT source_app FROM clipboard_history
+             WHERE source_app IS NOT NULL AND deleted_at IS NULL
+             ORDER BY source_app COLLATE NOCASE",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let apps = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(apps)
+}
@@ -0,0 +1,60 @@
+use super::history::import_items;
+use crate::database::Pool;
+use crate::importers;
+use crate::models::{ClipboardUpdateEvent, ImportReport, ImportSource};
+use std::path::PathBuf;
+use tauri::{Emitter, State};
+
+/// Desteklenen pano yöneticileri ve varsayılan konumlarında geçmiş bulunup bulunmadığı
+#[tauri::command]
+pub fn list_import_sources() -> Vec<ImportSource> {
+    importers::SOURCES
+        .iter()
+        .map(|(id, name)| {
+            let path = importers::default_path(id);
+            ImportSource {
+                id: id.to_string(),
+                name: name.to_string(),
+                found: path.as_ref().is_some_and(|p| p.exists()),
+                default_path: path.map(|p| p.display().to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Başka bir pano yöneticisinin geçmişini içe aktar; `dry_run` ile yalnızca önizleme yapılır
+#[tauri::command]
+pub async fn import_from_manager(
+    pool: State<'_, Pool>,
+    source: String,
+    path: Option<String>,
+    dry_run: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<ImportReport, String> {
+    let pool = pool.inner().clone();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        let path = path
+            .map(PathBuf::from)
+            .or_else(|| importers::default_path(&source))
+            .ok_or_else(|| format!("No history location known for '{}'", source))?;
+        let items = importers::parse(&source, &path)?;
+
+        let mut conn = pool.get();
+        import_items(&pool, &mut conn, items, dry_run)
+    })
+    .await
+    .map_err(|e| format!("Import task failed: {}", e))??;
+
+    if !report.dry_run && report.imported > 0 {
+        let event = ClipboardUpdateEvent {
+            action: "refresh".to_string(),
+            message: format!("Imported {} items", report.imported),
+        };
+        if let Err(e) = app_handle.emit("clipboard-update", event) {
+            eprintln!("Failed to send import event: {}", e);
+        }
+        crate::tray::refresh_menu(&app_handle);
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,7 @@
+use crate::instance;
+
+/// `--search` ile açıldıysa istenen aramayı bir kez döndür
+#[tauri::command]
+pub fn take_launch_search() -> Option<String> {
+    instance::take_pending_search()
+}
@@ -0,0 +1,36 @@
+use crate::database::{self, Pool};
+use crate::models::{ClipboardUpdateEvent, MaintenanceReport};
+use tauri::{Emitter, State};
+
+/// Bütünlük denetimi, onarım, VACUUM, ANALYZE ve WAL checkpoint; boyut öncesi/sonrası raporlanır.
+/// `quarantine` ise şifresi çözülemeyen öğeler geçmişten karantinaya taşınır.
+#[tauri::command]
+pub async fn run_database_maintenance(
+    pool: State<'_, Pool>,
+    quarantine: Option<bool>,
+    app_handle: tauri::AppHandle,
+) -> Result<MaintenanceReport, String> {
+    // VACUUM büyük veritabanlarında uzun sürebilir; UI bloklanmasın
+    let pool = pool.inner().clone();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        database::run_maintenance(&pool, quarantine.unwrap_or(false))
+    })
+    .await
+    .map_err(|e| format!("Maintenance task failed: {}", e))??;
+
+    if report.quarantined > 0 {
+        let event = ClipboardUpdateEvent {
+            action: "refresh".to_string(),
+            message: format!(
+                "{} unreadable items moved to quarantine",
+                report.quarantined
+            ),
+        };
+        if let Err(e) = app_handle.emit("clipboard-update", event) {
+            eprintln!("Failed to send maintenance event: {}", e);
+        }
+        crate::tray::refresh_menu(&app_handle);
+    }
+
+    Ok(report)
+}
@@ -1,10 +1,19 @@
-use crate::database;
+use crate::database::Pool;
+use tauri::State;
 
 /// Tüm kayıtların kategorilerini yeniden hesapla
 #[tauri::command]
-pub fn force_update_categories() -> String {
-    let conn = database::init_db();
+pub fn force_update_categories(pool: State<'_, Pool>) -> String {
+    recompute_categories(&pool)
+}
+
+/// Açılışta ve daemon'da komut dışında çağrılan sürüm
+pub fn recompute_categories(pool: &Pool) -> String {
+    pool.write(update_categories).unwrap_or_else(|e| e)
+}
 
+/// Güncellemeler tek işlemde yapılır; hata olursa hiçbiri uygulanmaz
+fn update_categories(conn: &rusqlite::Connection) -> Result<String, String> {
     // Image kayıtlarını güncelle
     let updated_images = conn
         .execute(
@@ -19,7 +28,7 @@ pub fn force_update_categories() -> String {
 
     let mut stmt = match conn.prepare(sql) {
         Ok(s) => s,
-        Err(e) => return format!("SQL Error: {}", e),
+        Err(e) => return Err(format!("SQL Error: {}", e)),
     };
 
     let rows: Vec<(i64, String, bool)> = match stmt.query_map([], |row| {
@@ -30,7 +39,7 @@ pub fn force_update_categories() -> String {
         ))
     }) {
         Ok(mapped) => mapped.filter_map(Result::ok).collect(),
-        Err(e) => return format!("Query Error: {}", e),
+        Err(e) => return Err(format!("Query Error: {}", e)),
     };
 
     let total = rows.len();
@@ -64,7 +73,7 @@ pub fn force_update_categories() -> String {
         .ok();
     }
 
-    format!(
+    Ok(format!(
         "Updated {} records: {} images, {} urls, {} emails, {} code, {} text",
         total + updated_images as usize,
         updated_images,
@@ -72,7 +81,7 @@ pub fn force_update_categories() -> String {
         email_count,
         code_count,
         text_count
-    )
+    ))
 }
 
 /// Kategori tespit fonksiyonu
@@ -1,13 +1,53 @@
+pub mod backup;
+pub mod bundle;
+pub mod capture;
+pub mod capture_limits;
 pub mod clear;
+pub mod copy;
+pub mod data_dir;
 pub mod delete;
+pub mod edit;
 pub mod history;
+pub mod ignored_apps;
+pub mod importers;
+pub mod launch;
+pub mod maintenance;
 pub mod migrate;
 pub mod pin;
+pub mod profiles;
+pub mod sensitive;
+pub mod settings;
+pub mod snippets;
+pub mod storage;
+pub mod tags;
+pub mod transfer;
+pub mod transform;
+pub mod trash;
 pub mod welcome;
 
+pub use backup::*;
+pub use bundle::*;
+pub use capture::*;
+pub use capture_limits::*;
 pub use clear::*;
+pub use copy::*;
+pub use data_dir::*;
 pub use delete::*;
+pub use edit::*;
 pub use history::*;
+pub use ignored_apps::*;
+pub use importers::*;
+pub use launch::*;
+pub use maintenance::*;
 pub use migrate::*;
 pub use pin::*;
+pub use profiles::*;
+pub use sensitive::*;
+pub use settings::*;
+pub use snippets::*;
+pub use storage::*;
+pub use tags::*;
+pub use transfer::*;
+pub use transform::*;
+pub use trash::*;
 pub use welcome::*;
@@ -1,26 +1,40 @@
-use crate::database;
-
-#[tauri::command]
-pub fn toggle_pin(id: i64) -> Result<(), String> {
-    let conn = database::init_db();
+use crate::database::Pool;
+use rusqlite::{Connection, OptionalExtension};
+use tauri::State;
 
+/// Sabitleme durumunu tersine çevir ve yeni durumu döndür
+pub fn toggle_pin_item(conn: &Connection, id: i64) -> Result<bool, String> {
     // Önce mevcut pin durumunu al
     let current_pinned: bool = conn
         .query_row(
-            "SELECT pinned FROM clipboard_history WHERE id = ?",
+            "SELECT pinned FROM clipboard_history WHERE id = ? AND deleted_at IS NULL",
             [id],
             |row| row.get(0),
         )
-        .map_err(|e| format!("Failed to get current pin status: {}", e))?;
+        .optional()
+        .map_err(|e| format!("Failed to get current pin status: {}", e))?
+        .ok_or_else(|| format!("Item {} not found", id))?;
 
     // Pin durumunu tersine çevir
     let new_pinned = !current_pinned;
 
     conn.execute(
         "UPDATE clipboard_history SET pinned = ? WHERE id = ?",
-        [new_pinned as i32, id as i32],
+        rusqlite::params![new_pinned, id],
     )
     .map_err(|e| format!("Failed to update pin status: {}", e))?;
 
+    Ok(new_pinned)
+}
+
+#[tauri::command]
+pub fn toggle_pin(
+    pool: State<'_, Pool>,
+    id: i64,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    pool.write(|conn| toggle_pin_item(conn, id))?;
+
+    crate::tray::refresh_menu(&app_handle);
     Ok(())
 }
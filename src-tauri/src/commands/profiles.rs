@@ -0,0 +1,38 @@
+use crate::models::Profile;
+use crate::profiles;
+
+#[tauri::command]
+pub fn list_profiles() -> Vec<Profile> {
+    profiles::list()
+}
+
+#[tauri::command]
+pub fn create_profile(name: String, never_capture: Option<bool>) -> Result<Profile, String> {
+    profiles::create(name.trim(), never_capture.unwrap_or(false))
+}
+
+/// Pano izleyicisi bundan sonra yalnızca bu profile kaydeder
+#[tauri::command]
+pub fn switch_profile(name: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    profiles::switch(&name)?;
+    profiles::notify_switched(&app_handle);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn set_profile_never_capture(
+    name: String,
+    never_capture: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    profiles::set_never_capture(&name, never_capture)?;
+    crate::tray::refresh_menu(&app_handle);
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_profile(name: String, app_handle: tauri::AppHandle) -> Result<(), String> {
+    profiles::delete(&name)?;
+    crate::tray::refresh_menu(&app_handle);
+    Ok(())
+}
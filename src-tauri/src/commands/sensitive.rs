@@ -0,0 +1,28 @@
+use crate::database::Pool;
+use tauri::State;
+
+#[tauri::command]
+pub fn toggle_sensitive(
+    pool: State<'_, Pool>,
+    id: i64,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    pool.write(|conn| {
+        let current: bool = conn
+            .query_row(
+                "SELECT sensitive FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+                [id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to get current sensitive status: {}", e))?;
+
+        conn.execute(
+            "UPDATE clipboard_history SET sensitive = ?1 WHERE id = ?2",
+            [!current as i64, id],
+        )
+        .map_err(|e| format!("Failed to update sensitive status: {}", e))
+    })?;
+
+    crate::tray::refresh_menu(&app_handle);
+    Ok(())
+}
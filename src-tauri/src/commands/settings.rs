@@ -0,0 +1,63 @@
+use crate::database::{self, Pool};
+use crate::models::{ClipboardUpdateEvent, Settings};
+use crate::settings;
+use rusqlite::Connection;
+use serde_json::Value;
+use tauri::{Emitter, State};
+
+#[tauri::command]
+pub fn get_settings(pool: State<'_, Pool>) -> Settings {
+    let conn = pool.get();
+    settings::load(&conn)
+}
+
+/// Yalnızca `patch` içindeki alanları değiştir (ör. `{"theme": "dark"}`)
+#[tauri::command]
+pub fn update_settings(
+    pool: State<'_, Pool>,
+    patch: Value,
+    app_handle: tauri::AppHandle,
+) -> Result<Settings, String> {
+    let settings = pool.write(|conn| {
+        let settings = settings::apply_patch(&settings::load(conn), patch)?;
+        store(conn, settings)
+    })?;
+    notify(&app_handle, settings)
+}
+
+#[tauri::command]
+pub fn reset_settings(
+    pool: State<'_, Pool>,
+    app_handle: tauri::AppHandle,
+) -> Result<Settings, String> {
+    let settings = pool.write(|conn| store(conn, Settings::default()))?;
+    notify(&app_handle, settings)
+}
+
+/// Kaydet ve yeni saklama kurallarını aynı işlemde hemen uygula; silinen öğe sayısı da döner
+fn store(conn: &Connection, settings: Settings) -> Result<(Settings, usize), String> {
+    settings::save(conn, &settings)?;
+    let removed = database::apply_retention(conn, &settings)?;
+    Ok((settings, removed))
+}
+
+/// Yeni ayarları ve saklama kurallarıyla silinen öğeleri bildir
+fn notify(
+    app_handle: &tauri::AppHandle,
+    (settings, removed): (Settings, usize),
+) -> Result<Settings, String> {
+    settings::notify_changed(app_handle, &settings);
+
+    if removed > 0 {
+        let event = ClipboardUpdateEvent {
+            action: "refresh".to_string(),
+            message: format!("{} items removed by retention settings", removed),
+        };
+        if let Err(e) = app_handle.emit("clipboard-update", event) {
+            eprintln!("Failed to send retention event: {}", e);
+        }
+        crate::tray::refresh_menu(app_handle);
+    }
+
+    Ok(settings)
+}
@@ -0,0 +1,243 @@
+use crate::clipboard::writer;
+use crate::database::Pool;
+use crate::models::Snippet;
+use crate::security;
+use crate::snippets::{self, Placeholder};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use tauri::State;
+
+const SNIPPET_COLUMNS: &str = "id, name, body, abbreviation, folder, created_at, updated_at";
+
+fn row_to_snippet(row: &rusqlite::Row) -> rusqlite::Result<Snippet> {
+    let encrypted_body: String = row.get(2)?;
+    // Çözülemeyen gövde boş gösterilir, şifreli metin arayüze sızmaz
+    let body = security::decrypt(&encrypted_body).unwrap_or_default();
+    let inputs = snippets::input_names(&body);
+
+    Ok(Snippet {
+        id: row.get(0)?,
+        name: row.get(1)?,
+        body,
+        abbreviation: row.get(3)?,
+        folder: row.get(4)?,
+        inputs,
+        created_at: row.get(5)?,
+        updated_at: row.get(6)?,
+    })
+}
+
+fn get_snippet_by_id(conn: &Connection, id: i64) -> Result<Snippet, String> {
+    let sql = format!("SELECT {} FROM snippets WHERE id = ?1", SNIPPET_COLUMNS);
+    conn.query_row(&sql, [id], row_to_snippet)
+        .optional()
+        .map_err(|e| format!("Failed to get snippet: {}", e))?
+        .ok_or_else(|| format!("Snippet {} not found", id))
+}
+
+/// Boş metinleri None'a çevir
+fn non_empty(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Yeni snippet ekle (`Pool::write` içinde çağrılır)
+fn insert_snippet(
+    conn: &Connection,
+    name: &str,
+    body: &str,
+    abbreviation: Option<String>,
+    folder: Option<String>,
+) -> Result<Snippet, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Snippet name cannot be empty".to_string());
+    }
+    let encrypted_body = security::encrypt(body)?;
+
+    conn.execute(
+        "INSERT INTO snippets (name, body, abbreviation, folder, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, datetime('now', 'localtime'), datetime('now', 'localtime'))",
+        params![name, encrypted_body, non_empty(abbreviation), non_empty(folder)],
+    )
+    .map_err(|e| format!("Failed to create snippet: {}", e))?;
+
+    get_snippet_by_id(conn, conn.last_insert_rowid())
+}
+
+#[tauri::command]
+pub fn list_snippets(
+    pool: State<'_, Pool>,
+    folder: Option<String>,
+) -> Result<Vec<Snippet>, String> {
+    let conn = pool.get();
+    let folder = non_empty(folder);
+
+    // Klasör verilirse alt klasörler de dahil edilir; adlardaki % ve _ joker sayılmasın diye LIKE kullanılmaz
+    let sql = format!(
+        "SELECT {} FROM snippets
+         WHERE ?1 IS NULL OR folder = ?1 OR substr(folder, 1, length(?1) + 1) = ?1 || '/'
+         ORDER BY folder COLLATE NOCASE, name COLLATE NOCASE",
+        SNIPPET_COLUMNS
+    );
+    let mut stmt = conn
+        .prepare(&sql)
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let snippets = stmt
+        .query_map([folder], row_to_snippet)
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(snippets)
+}
+
+#[tauri::command]
+pub fn list_snippet_folders(pool: State<'_, Pool>) -> Result<Vec<String>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT DISTINCT folder FROM snippets WHERE folder IS NOT NULL ORDER BY folder COLLATE NOCASE",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let folders = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(folders)
+}
+
+#[tauri::command]
+pub fn create_snippet(
+    pool: State<'_, Pool>,
+    name: String,
+    body: String,
+    abbreviation: Option<String>,
+    folder: Option<String>,
+) -> Result<Snippet, String> {
+    pool.write(|conn| insert_snippet(conn, &name, &body, abbreviation, folder))
+}
+
+#[tauri::command]
+pub fn update_snippet(
+    pool: State<'_, Pool>,
+    id: i64,
+    name: String,
+    body: String,
+    abbreviation: Option<String>,
+    folder: Option<String>,
+) -> Result<Snippet, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Snippet name cannot be empty".to_string());
+    }
+    let encrypted_body = security::encrypt(&body)?;
+
+    pool.write(|conn| {
+        let updated = conn
+            .execute(
+                "UPDATE snippets SET name = ?1, body = ?2, abbreviation = ?3, folder = ?4, updated_at = datetime('now', 'localtime') WHERE id = ?5",
+                params![name, encrypted_body, non_empty(abbreviation), non_empty(folder), id],
+            )
+            .map_err(|e| format!("Failed to update snippet: {}", e))?;
+        if updated == 0 {
+            return Err(format!("Snippet {} not found", id));
+        }
+
+        get_snippet_by_id(conn, id)
+    })
+}
+
+#[tauri::command]
+pub fn delete_snippet(pool: State<'_, Pool>, id: i64) -> Result<(), String> {
+    pool.write(|conn| {
+        conn.execute("DELETE FROM snippets WHERE id = ?1", [id])
+            .map_err(|e| format!("Failed to delete snippet: {}", e))?;
+        Ok(())
+    })
+}
+
+/// Kısaltmaya göre snippet bul
+#[tauri::command]
+pub fn find_snippet_by_abbreviation(
+    pool: State<'_, Pool>,
+    abbreviation: String,
+) -> Result<Option<Snippet>, String> {
+    let conn = pool.get();
+    let sql = format!(
+        "SELECT {} FROM snippets WHERE abbreviation = ?1",
+        SNIPPET_COLUMNS
+    );
+    conn.query_row(&sql, [abbreviation.trim()], row_to_snippet)
+        .optional()
+        .map_err(|e| format!("Failed to get snippet: {}", e))
+}
+
+/// Şablonu işle, sonucu panoya koy ve döndür
+#[tauri::command]
+pub fn expand_snippet(
+    pool: State<'_, Pool>,
+    id: i64,
+    inputs: Option<HashMap<String, String>>,
+) -> Result<String, String> {
+    let conn = pool.get();
+    let snippet = get_snippet_by_id(&conn, id)?;
+    let inputs = inputs.unwrap_or_default();
+
+    let rendered = snippets::render(&snippet.body, |placeholder| match placeholder {
+        // Tarih biçimlendirme SQLite strftime ile yapılır (yerel saat)
+        Placeholder::Date(format) => conn
+            .query_row("SELECT strftime(?1, 'now', 'localtime')", [format], |row| {
+                row.get::<_, Option<String>>(0)
+            })
+            .map(Option::unwrap_or_default)
+            .map_err(|e| format!("Invalid date format '{}': {}", format, e)),
+        Placeholder::Clipboard => Ok(writer::get_text().unwrap_or_default()),
+        Placeholder::Uuid => Ok(uuid::Uuid::new_v4().to_string()),
+        Placeholder::Input(name) => inputs
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("Missing input: {}", name)),
+    })?;
+
+    writer::set_text(&rendered)?;
+    Ok(rendered)
+}
+
+/// Geçmişteki bir metin öğesini snippet'e dönüştür
+#[tauri::command]
+pub fn promote_to_snippet(
+    pool: State<'_, Pool>,
+    id: i64,
+    name: String,
+    abbreviation: Option<String>,
+    folder: Option<String>,
+) -> Result<Snippet, String> {
+    pool.write(|conn| {
+        let (content, content_type, is_encrypted): (String, String, bool) = conn
+            .query_row(
+                "SELECT content, content_type, is_encrypted FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+                [id],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(|e| format!("Failed to get item: {}", e))?
+            .ok_or_else(|| format!("Item {} not found", id))?;
+
+        if content_type == "image" {
+            return Err("Image items cannot be saved as snippets".to_string());
+        }
+
+        let body = if is_encrypted {
+            security::decrypt(&content)?
+        } else {
+            content
+        };
+
+        insert_snippet(conn, &name, &body, abbreviation, folder)
+    })
+}
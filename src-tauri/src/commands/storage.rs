@@ -0,0 +1,53 @@
+use crate::database::Pool;
+use crate::models::StorageStats;
+use crate::security;
+use tauri::State;
+
+/// Sıkıştırmanın kazandırdığı alanı raporla
+#[tauri::command]
+pub fn get_storage_stats(pool: State<'_, Pool>) -> Result<StorageStats, String> {
+    let conn = pool.get();
+
+    let (item_count, stored_bytes): (i64, i64) = conn
+        .query_row(
+            "SELECT COUNT(*), COALESCE(SUM(length(content) + COALESCE(length(image_data), 0)), 0)
+             FROM clipboard_history",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|e| format!("Query failed: {}", e))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT content, image_data FROM clipboard_history
+             WHERE substr(content, 1, 2) = 'z:' OR substr(image_data, 1, 2) = 'z:'",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Option<String>>(1)?))
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok);
+
+    // Yalnızca sıkıştırılmış alanlar için fark hesaplanır
+    let mut compressed_items = 0;
+    let mut saved_bytes = 0i64;
+    for (content, image_data) in rows {
+        compressed_items += 1;
+        for data in std::iter::once(content).chain(image_data) {
+            if security::is_compressed(&data) {
+                let original = security::uncompressed_stored_size(&data).unwrap_or(data.len());
+                saved_bytes += original as i64 - data.len() as i64;
+            }
+        }
+    }
+
+    Ok(StorageStats {
+        item_count,
+        compressed_items,
+        stored_bytes,
+        uncompressed_bytes: stored_bytes + saved_bytes,
+        saved_bytes,
+    })
+}
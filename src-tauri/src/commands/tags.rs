@@ -0,0 +1,378 @@
+use crate::database::Pool;
+use crate::models::{ClipboardItem, ClipboardUpdateEvent, Collection, Tag};
+use rusqlite::{params, Connection, OptionalExtension};
+use std::collections::HashMap;
+use tauri::{Emitter, State};
+
+/// Etiket adını normalize et (boşlukları kırp, boşsa hata)
+fn normalize_tag_name(name: &str) -> Result<String, String> {
+    let trimmed = name.trim();
+    if trimmed.is_empty() {
+        return Err("Tag name cannot be empty".to_string());
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Etiketi bul, yoksa oluştur ve id'sini döndür
+fn get_or_create_tag(conn: &Connection, name: &str) -> Result<i64, String> {
+    conn.execute("INSERT OR IGNORE INTO tags (name) VALUES (?1)", [name])
+        .map_err(|e| format!("Failed to create tag: {}", e))?;
+
+    conn.query_row("SELECT id FROM tags WHERE name = ?1", [name], |row| {
+        row.get(0)
+    })
+    .map_err(|e| format!("Failed to get tag: {}", e))
+}
+
+fn find_tag_id(conn: &Connection, name: &str) -> Result<Option<i64>, String> {
+    conn.query_row("SELECT id FROM tags WHERE name = ?1", [name], |row| {
+        row.get(0)
+    })
+    .optional()
+    .map_err(|e| format!("Failed to get tag: {}", e))
+}
+
+/// Verilen öğelerin etiketlerini tek sorguda yükle
+pub(crate) fn load_tags_for_items(conn: &Connection, ids: &[i64]) -> HashMap<i64, Vec<String>> {
+    let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+    if ids.is_empty() {
+        return tags;
+    }
+
+    let placeholders = vec!["?"; ids.len()].join(",");
+    let sql = format!(
+        "SELECT it.item_id, t.name FROM item_tags it
+         JOIN tags t ON t.id = it.tag_id
+         WHERE it.item_id IN ({})
+         ORDER BY t.name COLLATE NOCASE",
+        placeholders
+    );
+
+    if let Ok(mut stmt) = conn.prepare(&sql) {
+        if let Ok(rows) = stmt.query_map(rusqlite::params_from_iter(ids), |row| {
+            Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+        }) {
+            for (item_id, name) in rows.filter_map(Result::ok) {
+                tags.entry(item_id).or_default().push(name);
+            }
+        }
+    }
+
+    tags
+}
+
+/// Öğe listesine etiketlerini ekle
+pub(crate) fn attach_tags(conn: &Connection, items: &mut [ClipboardItem]) {
+    let ids: Vec<i64> = items.iter().map(|item| item.id).collect();
+    let mut tags = load_tags_for_items(conn, &ids);
+    for item in items.iter_mut() {
+        item.tags = tags.remove(&item.id).unwrap_or_default();
+    }
+}
+
+/// Öğeye etiket ekle (etiket yoksa oluşturulur)
+pub(crate) fn tag_item(conn: &Connection, item_id: i64, tag: &str) -> Result<(), String> {
+    let name = normalize_tag_name(tag)?;
+    let tag_id = get_or_create_tag(conn, &name)?;
+    conn.execute(
+        "INSERT OR IGNORE INTO item_tags (item_id, tag_id) VALUES (?1, ?2)",
+        [item_id, tag_id],
+    )
+    .map_err(|e| format!("Failed to tag item: {}", e))?;
+    Ok(())
+}
+
+fn emit_refresh(app_handle: &tauri::AppHandle, message: &str) {
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: message.to_string(),
+    };
+
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send tag event: {}", e);
+    }
+}
+
+#[tauri::command]
+pub fn add_tag(
+    pool: State<'_, Pool>,
+    id: i64,
+    tag: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    pool.write(|conn| {
+        let exists: bool = conn
+            .query_row(
+                "SELECT EXISTS(SELECT 1 FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL)",
+                [id],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Failed to find item: {}", e))?;
+        if !exists {
+            return Err(format!("Item {} not found", id));
+        }
+        tag_item(conn, id, &tag)
+    })?;
+    emit_refresh(&app_handle, "Tag added");
+    Ok(())
+}
+
+#[tauri::command]
+pub fn remove_tag(
+    pool: State<'_, Pool>,
+    id: i64,
+    tag: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let name = normalize_tag_name(&tag)?;
+
+    pool.write(|conn| {
+        conn.execute(
+            "DELETE FROM item_tags WHERE item_id = ?1 AND tag_id = (SELECT id FROM tags WHERE name = ?2)",
+            params![id, name],
+        )
+        .map_err(|e| format!("Failed to remove tag: {}", e))
+    })?;
+
+    emit_refresh(&app_handle, "Tag removed");
+    Ok(())
+}
+
+/// Etiketleri öğe sayılarıyla listele (koleksiyonların etiketleri boş olsa da görünür)
+#[tauri::command]
+pub fn list_tags(pool: State<'_, Pool>) -> Result<Vec<Tag>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT t.id, t.name, COUNT(h.id) FROM tags t
+             LEFT JOIN item_tags it ON it.tag_id = t.id
+             LEFT JOIN clipboard_history h ON h.id = it.item_id AND h.deleted_at IS NULL
+             GROUP BY t.id
+             ORDER BY t.name COLLATE NOCASE",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let tags = stmt
+        .query_map([], |row| {
+            Ok(Tag {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                item_count: row.get(2)?,
+            })
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(tags)
+}
+
+#[tauri::command]
+pub fn rename_tag(
+    pool: State<'_, Pool>,
+    old_name: String,
+    new_name: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let old_name = normalize_tag_name(&old_name)?;
+    let new_name = normalize_tag_name(&new_name)?;
+
+    pool.write(|conn| {
+        let tag_id =
+            find_tag_id(conn, &old_name)?.ok_or_else(|| format!("Tag '{}' not found", old_name))?;
+
+        // Büyük/küçük harf değişikliği dışında hedef zaten varsa birleştirme gerekir
+        if let Some(existing) = find_tag_id(conn, &new_name)? {
+            if existing != tag_id {
+                return Err(format!(
+                    "Tag '{}' already exists, merge the tags instead",
+                    new_name
+                ));
+            }
+        }
+
+        conn.execute(
+            "UPDATE tags SET name = ?1 WHERE id = ?2",
+            params![new_name, tag_id],
+        )
+        .map_err(|e| format!("Failed to rename tag: {}", e))
+    })?;
+
+    emit_refresh(&app_handle, "Tag renamed");
+    Ok(())
+}
+
+/// `source` etiketini `target` içine birleştir; `source` silinir
+#[tauri::command]
+pub fn merge_tags(
+    pool: State<'_, Pool>,
+    source: String,
+    target: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let source = normalize_tag_name(&source)?;
+    let target = normalize_tag_name(&target)?;
+
+    let merged = pool.write(|conn| {
+        let source_id =
+            find_tag_id(conn, &source)?.ok_or_else(|| format!("Tag '{}' not found", source))?;
+        let target_id = get_or_create_tag(conn, &target)?;
+        if source_id == target_id {
+            return Ok(false);
+        }
+
+        conn.execute(
+            "INSERT OR IGNORE INTO item_tags (item_id, tag_id)
+             SELECT item_id, ?2 FROM item_tags WHERE tag_id = ?1",
+            [source_id, target_id],
+        )
+        .map_err(|e| format!("Failed to merge tags: {}", e))?;
+
+        // Kaynak etikete bağlı koleksiyonlar hedefe taşınır
+        conn.execute(
+            "UPDATE collections SET tag_id = ?2 WHERE tag_id = ?1",
+            [source_id, target_id],
+        )
+        .map_err(|e| format!("Failed to move collections: {}", e))?;
+
+        conn.execute("DELETE FROM tags WHERE id = ?1", [source_id])
+            .map_err(|e| format!("Failed to delete tag: {}", e))?;
+        Ok(true)
+    })?;
+    if !merged {
+        return Ok(());
+    }
+
+    emit_refresh(&app_handle, "Tags merged");
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_tag(
+    pool: State<'_, Pool>,
+    name: String,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let name = normalize_tag_name(&name)?;
+
+    pool.write(|conn| {
+        conn.execute("DELETE FROM tags WHERE name = ?1", [&name])
+            .map_err(|e| format!("Failed to delete tag: {}", e))
+    })?;
+
+    emit_refresh(&app_handle, "Tag deleted");
+    Ok(())
+}
+
+/// Koleksiyon oluştur; etiketine sahip öğeler saklama kurallarından muaf olur
+#[tauri::command]
+pub fn create_collection(
+    pool: State<'_, Pool>,
+    name: String,
+    tag: String,
+    query: Option<String>,
+    content_filter: Option<String>,
+) -> Result<Collection, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err("Collection name cannot be empty".to_string());
+    }
+    let tag = normalize_tag_name(&tag)?;
+
+    pool.write(|conn| {
+        let tag_id = get_or_create_tag(conn, &tag)?;
+        conn.execute(
+            "INSERT INTO collections (name, tag_id, query, content_filter, created_at) VALUES (?1, ?2, ?3, ?4, datetime('now', 'localtime'))",
+            params![name, tag_id, query, content_filter],
+        )
+        .map_err(|e| format!("Failed to create collection: {}", e))?;
+
+        let id = conn.last_insert_rowid();
+        get_collection(conn, id)?.ok_or_else(|| "Collection not found".to_string())
+    })
+}
+
+fn get_collection(conn: &Connection, id: i64) -> Result<Option<Collection>, String> {
+    conn.query_row(
+        "SELECT c.id, c.name, t.name, c.query, c.content_filter, c.created_at
+         FROM collections c JOIN tags t ON t.id = c.tag_id
+         WHERE c.id = ?1",
+        [id],
+        |row| {
+            Ok(Collection {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                tag: row.get(2)?,
+                query: row.get(3)?,
+                content_filter: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| format!("Failed to get collection: {}", e))
+}
+
+#[tauri::command]
+pub fn list_collections(pool: State<'_, Pool>) -> Result<Vec<Collection>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT c.id, c.name, t.name, c.query, c.content_filter, c.created_at
+             FROM collections c JOIN tags t ON t.id = c.tag_id
+             ORDER BY c.name COLLATE NOCASE",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let collections = stmt
+        .query_map([], |row| {
+            Ok(Collection {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                tag: row.get(2)?,
+                query: row.get(3)?,
+                content_filter: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(collections)
+}
+
+/// Koleksiyonu sil (etiket ve öğeler korunur)
+#[tauri::command]
+pub fn delete_collection(pool: State<'_, Pool>, id: i64) -> Result<(), String> {
+    pool.write(|conn| {
+        conn.execute("DELETE FROM collections WHERE id = ?1", [id])
+            .map_err(|e| format!("Failed to delete collection: {}", e))?;
+        Ok(())
+    })
+}
+
+/// Koleksiyonun kayıtlı filtresiyle arama yap
+#[tauri::command]
+pub async fn get_collection_items(
+    pool: State<'_, Pool>,
+    id: i64,
+    limit: Option<i32>,
+    offset: Option<i32>,
+) -> Result<Vec<ClipboardItem>, String> {
+    let collection = {
+        let conn = pool.get();
+        get_collection(&conn, id)?.ok_or_else(|| format!("Collection {} not found", id))?
+    };
+
+    super::search_clipboard_history(
+        pool,
+        collection.query.unwrap_or_default(),
+        limit,
+        offset,
+        collection.content_filter,
+        Some(collection.tag),
+        None,
+    )
+    .await
+}
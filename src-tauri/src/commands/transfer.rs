@@ -0,0 +1,129 @@
+use super::history::ImportSession;
+use crate::database::Pool;
+use crate::export::{self, CountingReader};
+use crate::models::{ClipboardUpdateEvent, ImportReport, TransferProgress};
+use std::fs::File;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tauri::{Emitter, State};
+
+// Arayüzden gelen iptal isteği; her aktarım başında sıfırlanır
+static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+// Olaylar arayüzü boğmasın diye en fazla bu sıklıkla gönderilir
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Süren aktarımın ilerlemesini bildirir ve iptal isteğini denetler
+pub struct Transfer {
+    app_handle: Option<tauri::AppHandle>,
+    operation: &'static str,
+    total: Option<u64>,
+    last_emit: Option<Instant>,
+}
+
+impl Transfer {
+    pub fn begin(app_handle: Option<tauri::AppHandle>, operation: &'static str) -> Self {
+        CANCEL_REQUESTED.store(false, Ordering::SeqCst);
+        Self {
+            app_handle,
+            operation,
+            total: None,
+            last_emit: None,
+        }
+    }
+
+    pub fn set_total(&mut self, total: u64) {
+        self.total = Some(total);
+    }
+
+    /// İlerlemeyi bildir; iptal istendiyse hata döner ve aktarım durur
+    pub fn progress(&mut self, items: usize, done: u64) -> Result<(), String> {
+        if CANCEL_REQUESTED.load(Ordering::SeqCst) {
+            return Err("Transfer cancelled".to_string());
+        }
+        if self
+            .last_emit
+            .is_none_or(|last| last.elapsed() >= PROGRESS_INTERVAL)
+        {
+            self.last_emit = Some(Instant::now());
+            self.emit(items, done, false);
+        }
+        Ok(())
+    }
+
+    pub fn finish(&self, items: usize, done: u64) {
+        self.emit(items, done, true);
+    }
+
+    fn emit(&self, items: usize, done: u64, finished: bool) {
+        let Some(app_handle) = &self.app_handle else {
+            return;
+        };
+        let progress = TransferProgress {
+            operation: self.operation.to_string(),
+            items,
+            done,
+            total: self.total,
+            finished,
+        };
+        if let Err(e) = app_handle.emit("transfer-progress", progress) {
+            eprintln!("Failed to send transfer progress: {}", e);
+        }
+    }
+}
+
+/// Süren dışa/içe aktarmayı iptal et; içe aktarmada hiçbir öğe eklenmez
+#[tauri::command]
+pub fn cancel_transfer() {
+    CANCEL_REQUESTED.store(true, Ordering::SeqCst);
+}
+
+/// Dışa aktarılmış dosyayı (arşiv, NDJSON veya JSON) satır satır okuyup içe aktar
+#[tauri::command]
+pub async fn import_history_file(
+    pool: State<'_, Pool>,
+    path: String,
+    dry_run: Option<bool>,
+    app_handle: tauri::AppHandle,
+) -> Result<ImportReport, String> {
+    let dry_run = dry_run.unwrap_or(false);
+
+    let handle = app_handle.clone();
+    let pool = pool.inner().clone();
+    let report = tauri::async_runtime::spawn_blocking(move || {
+        let file = File::open(&path).map_err(|e| format!("Failed to open {}: {}", path, e))?;
+        let size = file
+            .metadata()
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?
+            .len();
+        let (reader, bytes_read) = CountingReader::new(file);
+
+        let mut transfer = Transfer::begin(Some(handle), "import");
+        transfer.set_total(size);
+
+        let mut conn = pool.get();
+        let mut session = ImportSession::begin(&pool, &mut conn, dry_run)?;
+        export::read_items(reader, |item| {
+            session.add(item)?;
+            transfer.progress(session.processed(), bytes_read.get())
+        })?;
+        transfer.finish(session.processed(), bytes_read.get());
+
+        session.finish()
+    })
+    .await
+    .map_err(|e| format!("Import task failed: {}", e))??;
+
+    if !report.dry_run {
+        let event = ClipboardUpdateEvent {
+            action: "refresh".to_string(),
+            message: format!("Imported {} items", report.imported),
+        };
+        if let Err(e) = app_handle.emit("clipboard-update", event) {
+            eprintln!("Failed to send import event: {}", e);
+        }
+        crate::tray::refresh_menu(&app_handle);
+    }
+
+    Ok(report)
+}
@@ -0,0 +1,81 @@
+use crate::clipboard::writer;
+use crate::database::{self, Pool};
+use crate::models::ClipboardUpdateEvent;
+use crate::security;
+use crate::transform;
+use rusqlite::{params, OptionalExtension};
+use tauri::{Emitter, State};
+
+/// Kullanılabilir dönüşüm adlarını döndür
+#[tauri::command]
+pub fn list_transforms() -> Vec<String> {
+    transform::TRANSFORMS
+        .iter()
+        .map(|t| t.to_string())
+        .collect()
+}
+
+/// Öğeye dönüşüm zincirini uygula.
+/// `save_as_new` ise sonuç orijinale bağlı yeni bir geçmiş kaydı olur, değilse panoya yazılır.
+#[tauri::command]
+pub fn transform_item(
+    pool: State<'_, Pool>,
+    id: i64,
+    pipeline: Vec<String>,
+    save_as_new: bool,
+    app_handle: tauri::AppHandle,
+) -> Result<String, String> {
+    let conn = pool.get();
+
+    let (content, content_type, is_encrypted): (String, String, bool) = conn
+        .query_row(
+            "SELECT content, content_type, is_encrypted FROM clipboard_history WHERE id = ?1 AND deleted_at IS NULL",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .map_err(|e| format!("Failed to get item: {}", e))?
+        .ok_or_else(|| format!("Item {} not found", id))?;
+
+    if content_type == "image" {
+        return Err("Image items cannot be transformed".to_string());
+    }
+
+    let content = if is_encrypted {
+        security::decrypt(&content)?
+    } else {
+        content
+    };
+
+    let result = transform::apply_pipeline(&pipeline, &content)?;
+
+    if !save_as_new {
+        writer::set_text(&result)?;
+        return Ok(result);
+    }
+
+    if result.trim().is_empty() {
+        return Err("Transform produced empty content".to_string());
+    }
+
+    let category = database::detect_category(&result);
+    let encrypted = security::encrypt(&result)?;
+    pool.write(|conn| {
+        conn.execute(
+            "INSERT INTO clipboard_history (content, content_type, category, created_at, is_encrypted, source_item_id) VALUES (?1, 'text', ?2, datetime('now', 'localtime'), 1, ?3)",
+            params![encrypted, category, id],
+        )
+        .map_err(|e| format!("Failed to save transformed item: {}", e))
+    })?;
+
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: "Transformed item added".to_string(),
+    };
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send transform event: {}", e);
+    }
+    crate::tray::refresh_menu(&app_handle);
+
+    Ok(result)
+}
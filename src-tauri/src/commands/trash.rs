@@ -0,0 +1,126 @@
+use crate::database::Pool;
+use crate::models::{ClipboardUpdateEvent, TrashedItem};
+use crate::security;
+use tauri::{Emitter, State};
+
+/// Bir silme işleminde çöp kutusuna taşınan öğeleri gruplayan anahtar
+pub fn new_undo_token() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn emit_refresh(app_handle: &tauri::AppHandle, message: String) {
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message,
+    };
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send trash event: {}", e);
+    }
+    crate::tray::refresh_menu(app_handle);
+}
+
+/// Çöp kutusundaki öğeler, en son silinen önce
+#[tauri::command]
+pub fn list_trash(pool: State<'_, Pool>) -> Result<Vec<TrashedItem>, String> {
+    let conn = pool.get();
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, content, content_type, COALESCE(category, 'text'), created_at, deleted_at, sensitive, is_encrypted
+             FROM clipboard_history WHERE deleted_at IS NOT NULL
+             ORDER BY deleted_at DESC, id DESC",
+        )
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+
+    let items = stmt
+        .query_map([], |row| {
+            let content: String = row.get(1)?;
+            let is_encrypted: bool = row.get(7)?;
+            Ok(TrashedItem {
+                id: row.get(0)?,
+                content: if is_encrypted {
+                    security::decrypt(&content).unwrap_or_default()
+                } else {
+                    content
+                },
+                content_type: row.get(2)?,
+                category: row.get(3)?,
+                created_at: row.get(4)?,
+                deleted_at: row.get(5)?,
+                sensitive: row.get(6)?,
+            })
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+
+    Ok(items)
+}
+
+/// Öğeyi çöp kutusundan geçmişe geri al
+#[tauri::command]
+pub fn restore_item(
+    pool: State<'_, Pool>,
+    id: i64,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let restored = pool.write(|conn| {
+        conn.execute(
+            "UPDATE clipboard_history SET deleted_at = NULL, undo_token = NULL
+             WHERE id = ?1 AND deleted_at IS NOT NULL",
+            [id],
+        )
+        .map_err(|e| format!("Restore error: {}", e))
+    })?;
+    if restored == 0 {
+        return Err(format!("Item {} is not in the trash", id));
+    }
+
+    emit_refresh(&app_handle, "Item restored from trash".to_string());
+    Ok(())
+}
+
+/// Silme veya temizleme işlemini geri al; geri alınan öğe sayısını döndürür
+#[tauri::command]
+pub fn undo_delete(
+    pool: State<'_, Pool>,
+    undo_token: String,
+    app_handle: tauri::AppHandle,
+) -> Result<usize, String> {
+    let restored = pool.write(|conn| {
+        conn.execute(
+            "UPDATE clipboard_history SET deleted_at = NULL, undo_token = NULL
+             WHERE undo_token = ?1 AND deleted_at IS NOT NULL",
+            [&undo_token],
+        )
+        .map_err(|e| format!("Undo error: {}", e))
+    })?;
+    if restored == 0 {
+        return Err("Nothing to undo; the items were restored or purged".to_string());
+    }
+
+    emit_refresh(&app_handle, format!("{} items restored", restored));
+    Ok(restored)
+}
+
+/// Çöp kutusunu kalıcı olarak boşalt; silinen öğe sayısını döndürür
+#[tauri::command]
+pub fn empty_trash(pool: State<'_, Pool>, app_handle: tauri::AppHandle) -> Result<usize, String> {
+    let removed = pool.write(|conn| {
+        let removed = conn
+            .execute(
+                "DELETE FROM clipboard_history WHERE deleted_at IS NOT NULL",
+                [],
+            )
+            .map_err(|e| format!("Failed to empty trash: {}", e))?;
+        // Boşalan sayfalar diske geri verilir (artımlı VACUUM kipindeki veritabanlarında)
+        conn.execute_batch("PRAGMA incremental_vacuum")
+            .map_err(|e| format!("Vacuum error: {}", e))?;
+        Ok(removed)
+    })?;
+
+    emit_refresh(
+        &app_handle,
+        format!("{} items permanently deleted", removed),
+    );
+    Ok(removed)
+}
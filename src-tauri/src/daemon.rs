@@ -0,0 +1,154 @@
+use crate::backup;
+use crate::clipboard;
+use crate::commands;
+use crate::database::{self, Pool};
+use crate::ipc;
+use crate::models::ClipboardUpdateEvent;
+use rusqlite::Connection;
+use std::fs::File;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+use tauri::Emitter;
+
+// Panoyu izleyen sürecin tuttuğu kilit; aynı anda yalnızca bir izleyici çalışır
+const LOCK_FILE: &str = "watcher.lock";
+// Bağlı arayüzün veritabanı değişikliklerini ve daemon'u denetleme aralığı
+const ATTACH_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+// Arayüz, panoyu başka bir süreç (daemon) izlerken ona bağlıysa
+static ATTACHED: AtomicBool = AtomicBool::new(false);
+
+/// Pano bu süreçte değil de çalışan bir daemon tarafından mı izleniyor
+pub fn is_attached() -> bool {
+    ATTACHED.load(Ordering::SeqCst)
+}
+
+/// İzleyici kilidini almayı dene; başka bir süreç panoyu izliyorsa `None`
+pub fn try_lock_watcher() -> Result<Option<File>, String> {
+    database::try_lock(LOCK_FILE)
+}
+
+/// `--daemon`: webview açmadan pano izleyicisini ve soket sunucusunu çalıştır; dönen değer çıkış kodudur
+pub fn run() -> i32 {
+    let lock = match try_lock_watcher() {
+        Ok(Some(lock)) => lock,
+        Ok(None) => {
+            eprintln!("Another ClipCrab instance is already capturing the clipboard");
+            return 1;
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return 1;
+        }
+    };
+
+    // Veritabanı migration'ını çalıştır
+    let pool = Pool::new();
+    let _ = commands::recompute_categories(&pool);
+
+    println!("ClipCrab daemon started (pid {})", std::process::id());
+    ipc::start(pool.clone(), None);
+    backup::start_scheduler(pool.clone());
+    let watcher = clipboard::start_clipboard_watcher(pool, None);
+
+    // İzleyici yalnızca panoya erişemezse durur; systemd yeniden başlatabilsin diye hatayla çık
+    let _ = watcher.join();
+    drop(lock);
+    eprintln!("Clipboard watcher stopped");
+    1
+}
+
+/// Arayüz başlangıcı: daemon çalışıyorsa ona bağlan, yoksa izleyiciyi bu süreçte başlat
+pub fn start_or_attach(pool: Pool, app_handle: tauri::AppHandle) {
+    match try_lock_watcher() {
+        Ok(Some(lock)) => start_watcher(pool, app_handle, lock),
+        Ok(None) => {
+            println!("Attaching to running ClipCrab daemon");
+            attach(pool, app_handle);
+        }
+        Err(e) => {
+            // Kilit kullanılamıyorsa eskisi gibi izleyiciyi yine de başlat
+            eprintln!("{}", e);
+            clipboard::start_clipboard_watcher(pool, Some(app_handle));
+        }
+    }
+}
+
+/// İzleyiciyi ve ona bağlı soket sunucusunu bu süreçte başlat
+fn start_watcher(pool: Pool, app_handle: tauri::AppHandle, lock: File) {
+    ipc::start(pool.clone(), Some(app_handle.clone()));
+    backup::start_scheduler(pool.clone());
+    let watcher = clipboard::start_clipboard_watcher(pool, Some(app_handle));
+    // Kilit izleyici çalıştığı sürece tutulur
+    thread::spawn(move || {
+        let _ = watcher.join();
+        drop(lock);
+    });
+}
+
+/// Başka bağlantıların yaptığı her işlemde artan sayaç
+fn data_version(conn: &Connection) -> i64 {
+    conn.query_row("PRAGMA data_version", [], |row| row.get(0))
+        .unwrap_or(0)
+}
+
+/// Daemon'un kaydettiği öğeleri arayüze yansıt; daemon kapanırsa izlemeyi devral
+fn attach(pool: Pool, app_handle: tauri::AppHandle) {
+    ATTACHED.store(true, Ordering::SeqCst);
+    thread::spawn(move || {
+        let mut conn = database::init_db_for_watcher(&pool);
+        let mut conn_dir = database::profile_dir();
+        let mut last_version = data_version(&conn);
+        let mut last_paused = clipboard::capture::is_paused();
+
+        loop {
+            thread::sleep(ATTACH_POLL_INTERVAL);
+
+            match try_lock_watcher() {
+                Ok(Some(lock)) => {
+                    println!("ClipCrab daemon stopped, starting clipboard watcher");
+                    ATTACHED.store(false, Ordering::SeqCst);
+                    start_watcher(pool, app_handle, lock);
+                    return;
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("{}", e),
+            }
+
+            // Duraklatma daemon tarafından uygulanır; süresi dolunca veya başka süreçte değişince tepsiyi güncelle
+            let paused = clipboard::capture::is_paused();
+            if paused != last_paused {
+                last_paused = paused;
+                clipboard::capture::notify_status_changed(&app_handle);
+            }
+
+            // Profil değiştiyse yeni profilin veritabanını izle
+            if crate::profiles::refresh() {
+                crate::profiles::notify_switched(&app_handle);
+            }
+            let dir = database::profile_dir();
+            if dir != conn_dir {
+                conn = database::init_db_for_watcher(&pool);
+                conn_dir = dir;
+                last_version = data_version(&conn);
+                continue;
+            }
+
+            let version = data_version(&conn);
+            if version == last_version {
+                continue;
+            }
+            last_version = version;
+
+            let event = ClipboardUpdateEvent {
+                action: "refresh".to_string(),
+                message: "History changed by ClipCrab daemon".to_string(),
+            };
+            if let Err(e) = app_handle.emit("clipboard-update", event) {
+                eprintln!("Failed to send daemon update event: {}", e);
+            }
+            crate::tray::refresh_menu(&app_handle);
+        }
+    });
+}
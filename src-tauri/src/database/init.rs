@@ -1,85 +1,211 @@
+use super::pool::Pool;
+use lazy_static::lazy_static;
 use rusqlite::Connection;
-use std::path::PathBuf;
-use std::sync::Once;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
-// Tablo oluşturma sadece bir kez
-static INIT: Once = Once::new();
-// Migration kontrolü için
-static MIGRATION: Once = Once::new();
+/// Şemanın sürümü (`PRAGMA user_version`); tablo veya sütun eklendiğinde artırılır
+pub const SCHEMA_VERSION: i32 = 3;
+
+lazy_static! {
+    // Tabloları oluşturulmuş ve migration'ı çalışmış veritabanları (her profilin ayrı dosyası var)
+    static ref PREPARED: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+}
+
+/// Etkin profilin veritabanı ve anahtarının bulunduğu klasör
+pub fn profile_dir() -> PathBuf {
+    crate::profiles::active_dir()
+}
 
 pub fn get_db_path() -> PathBuf {
-    let mut path = dirs::data_dir().expect("User data directory not found");
-    path.push("clipcrab");
-    std::fs::create_dir_all(&path).expect("Failed to create data directory");
-    path.push("clipboard.db");
-    path
+    let dir = profile_dir();
+    if let Err(e) = std::fs::create_dir_all(&dir) {
+        eprintln!(
+            "Failed to create profile directory {}: {}",
+            dir.display(),
+            e
+        );
+    }
+    dir.join("clipboard.db")
 }
 
-pub fn init_db() -> Connection {
-    let db_path = get_db_path();
-    let conn = Connection::open(&db_path).expect("Failed to open database");
+/// Tabloları oluştur ve migration'ı çalıştır; her veritabanı için süreç başına bir kez
+pub(super) fn prepare_schema(pool: &Pool, db_path: &Path, conn: &Connection) {
+    let mut prepared = PREPARED.lock().unwrap();
+    if !prepared.insert(db_path.to_path_buf()) {
+        return;
+    }
 
-    // SQLite performans optimizasyonları
-    conn.execute_batch(
-        "PRAGMA journal_mode = WAL;
-         PRAGMA synchronous = NORMAL;
-         PRAGMA cache_size = 10000;
-         PRAGMA temp_store = MEMORY;",
+    // Ana tabloyu oluştur (şifrelenmiş içerik için)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS clipboard_history (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            content TEXT NOT NULL,
+            content_type TEXT DEFAULT 'text',
+            category TEXT DEFAULT 'text',
+            image_data TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            pinned INTEGER DEFAULT 0,
+            is_encrypted INTEGER DEFAULT 1,
+            source_item_id INTEGER,
+            sensitive INTEGER DEFAULT 0,
+            source_app TEXT,
+            deleted_at TEXT,
+            undo_token TEXT
+        )",
+        [],
+    )
+    .expect("Failed to create table");
+
+    // Index oluştur (sorgu performansı için)
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_pinned_id ON clipboard_history(pinned DESC, id DESC)",
+        [],
     )
     .ok();
 
-    // Tablo oluşturma sadece bir kere çalışsın
-    INIT.call_once(|| {
-        // Ana tabloyu oluştur (şifrelenmiş içerik için)
-        conn.execute(
-            "CREATE TABLE IF NOT EXISTS clipboard_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                content TEXT NOT NULL,
-                content_type TEXT DEFAULT 'text',
-                category TEXT DEFAULT 'text',
-                image_data TEXT,
-                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
-                pinned INTEGER DEFAULT 0,
-                is_encrypted INTEGER DEFAULT 1
-            )",
-            [],
-        )
-        .expect("Failed to create table");
-
-        // Index oluştur (sorgu performansı için)
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_clipboard_pinned_id ON clipboard_history(pinned DESC, id DESC)",
-            [],
-        )
-        .ok();
-
-        // Kategori için index (filtreleme hızı için)
-        conn.execute(
-            "CREATE INDEX IF NOT EXISTS idx_clipboard_category ON clipboard_history(category)",
-            [],
-        )
-        .ok();
-    });
-
-    // Migration her başlatmada bir kez çalışsın
-    MIGRATION.call_once(|| {
-        super::migrate::migrate_database(&conn);
-    });
-
-    conn
-}
+    // Kategori için index (filtreleme hızı için)
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_category ON clipboard_history(category)",
+        [],
+    )
+    .ok();
 
-// Watcher için ayrı bağlantı (uzun süreli kullanım için)
-pub fn init_db_for_watcher() -> Connection {
-    let db_path = get_db_path();
-    let conn = Connection::open(&db_path).expect("Failed to open database");
+    // Etiket tabloları (çoktan çoğa ilişki)
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS tags (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE COLLATE NOCASE
+        );
+        CREATE TABLE IF NOT EXISTS item_tags (
+            item_id INTEGER NOT NULL REFERENCES clipboard_history(id) ON DELETE CASCADE,
+            tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+            PRIMARY KEY (item_id, tag_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_item_tags_tag ON item_tags(tag_id);",
+    )
+    .expect("Failed to create tag tables");
+
+    // Koleksiyonlar: bir etikete bağlı kayıtlı görünümler
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS collections (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE,
+            tag_id INTEGER NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+            query TEXT,
+            content_filter TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )
+    .expect("Failed to create collections table");
 
-    // WAL mode performans için
+    // Snippet kütüphanesi (geçmişten bağımsız, gövde şifreli)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS snippets (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL,
+            body TEXT NOT NULL,
+            abbreviation TEXT UNIQUE,
+            folder TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )
+    .expect("Failed to create snippets table");
+
+    // Düzenlenen öğelerin önceki sürümleri (şifreli)
     conn.execute_batch(
-        "PRAGMA journal_mode = WAL;
-         PRAGMA synchronous = NORMAL;",
+        "CREATE TABLE IF NOT EXISTS item_revisions (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL REFERENCES clipboard_history(id) ON DELETE CASCADE,
+            content TEXT NOT NULL,
+            category TEXT DEFAULT 'text',
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        );
+        CREATE INDEX IF NOT EXISTS idx_item_revisions_item ON item_revisions(item_id, id DESC);",
     )
-    .ok();
+    .expect("Failed to create revisions table");
+
+    // Kaydedilmeyecek uygulamalar (WM_CLASS veya süreç adı)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS ignored_apps (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            name TEXT NOT NULL UNIQUE COLLATE NOCASE,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )
+    .expect("Failed to create ignored_apps table");
+
+    // Anahtar/değer ayarları (değerler JSON)
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS settings (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        )",
+        [],
+    )
+    .expect("Failed to create settings table");
+
+    // Boyut sınırları nedeniyle atlanan veya kırpılan kayıtlar
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS capture_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            content_type TEXT NOT NULL,
+            action TEXT NOT NULL,
+            reason TEXT NOT NULL,
+            size INTEGER NOT NULL,
+            source_app TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )
+    .expect("Failed to create capture_log table");
+
+    // Şifresi çözülemeyen ve bakım sırasında geçmişten ayrılan öğeler
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS quarantine (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            item_id INTEGER NOT NULL,
+            content TEXT NOT NULL,
+            content_type TEXT,
+            category TEXT,
+            image_data TEXT,
+            created_at TEXT,
+            source_app TEXT,
+            reason TEXT NOT NULL,
+            quarantined_at TEXT DEFAULT CURRENT_TIMESTAMP
+        )",
+        [],
+    )
+    .expect("Failed to create quarantine table");
+
+    super::migrate::migrate_database(conn);
+    // Saklama kuralları ilk kez çalışmadan önce
+    if let Err(e) = crate::settings::migrate_legacy(conn) {
+        eprintln!("Failed to migrate settings: {}", e);
+    }
+    if let Err(e) = conn.pragma_update(None, "user_version", SCHEMA_VERSION) {
+        eprintln!("Failed to set schema version: {}", e);
+    }
+
+    // Sıkıştırma öncesinden kalan büyük kayıtlar açılışı bekletmemek için arka planda sıkıştırılır
+    if !super::maintenance::existing_rows_compressed(conn) {
+        super::maintenance::compress_existing_rows_in_background(pool, db_path);
+    }
+}
+
+/// Veritabanının içeriği değiştiyse (ör. geri yükleme) tabloları ve migration'ı yeniden çalıştır
+pub fn upgrade_schema(pool: &Pool, conn: &Connection) {
+    let db_path = get_db_path();
+    PREPARED.lock().unwrap().remove(&db_path);
+    prepare_schema(pool, &db_path, conn);
+}
 
-    conn
+// Havuz dışında, süreç boyunca açık kalan bağlantı (ör. `PRAGMA data_version` takibi için)
+pub fn init_db_for_watcher(pool: &Pool) -> Connection {
+    pool.open(&get_db_path())
 }
@@ -0,0 +1,464 @@
+use super::Pool;
+use crate::models::{DataDirInfo, DataDirSource};
+use lazy_static::lazy_static;
+use rusqlite::Connection;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const DATA_DIR_ENV: &str = "CLIPCRAB_DATA_DIR";
+// Taşınabilir kurulumda çalıştırılabilir dosyanın yanındaki klasör; varsa kendiliğinden kullanılır
+const PORTABLE_DIR: &str = "clipcrab-data";
+// Varsayılan klasörde, taşınmış verinin yolunu tutan dosya
+const LOCATION_FILE: &str = "location";
+// Taşınan dosyalar (WAL dosyaları VACUUM INTO ile birleşir)
+const DB_FILE: &str = "clipboard.db";
+const KEY_FILE: &str = "key.bin";
+const PROFILES_FILE: &str = "profiles.json";
+
+lazy_static! {
+    static ref DATA_DIR: Mutex<Option<(PathBuf, DataDirSource)>> = Mutex::new(None);
+}
+
+#[cfg(test)]
+lazy_static! {
+    // Veri klasörü süreç geneli olduğundan onu değiştiren testler sırayla çalışır
+    pub(crate) static ref TEST_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// `--data-dir <yol>` ve `--portable` seçeneklerine göre veri klasörünü belirle.
+/// Süreç başında, veritabanı açılmadan önce çağrılmalıdır; diğer argümanlar yok sayılır.
+pub fn configure_data_dir(args: &[String]) -> Result<PathBuf, String> {
+    let mut flag = None;
+    let mut portable = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data-dir" => {
+                let path = args.next().ok_or("Option --data-dir requires a value")?;
+                flag = Some(PathBuf::from(path));
+            }
+            "--portable" => portable = true,
+            _ => {
+                if let Some(path) = arg.strip_prefix("--data-dir=") {
+                    flag = Some(PathBuf::from(path));
+                }
+            }
+        }
+    }
+
+    let (path, source) = resolve(flag, portable)?;
+    fs::create_dir_all(&path)
+        .map_err(|e| format!("Failed to create data directory {}: {}", path.display(), e))?;
+    *DATA_DIR.lock().unwrap() = Some((path.clone(), source));
+    Ok(path)
+}
+
+fn resolve(flag: Option<PathBuf>, portable: bool) -> Result<(PathBuf, DataDirSource), String> {
+    if let Some(path) = flag {
+        return Ok((absolute(&path)?, DataDirSource::Flag));
+    }
+    if let Some(path) = std::env::var_os(DATA_DIR_ENV).filter(|p| !p.is_empty()) {
+        return Ok((absolute(Path::new(&path))?, DataDirSource::Env));
+    }
+
+    let portable_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join(PORTABLE_DIR)));
+    if let Some(dir) = portable_dir {
+        if portable || dir.is_dir() {
+            return Ok((dir, DataDirSource::Portable));
+        }
+    } else if portable {
+        return Err("Could not locate the executable for portable mode".to_string());
+    }
+
+    let default = default_dir()?;
+    match fs::read_to_string(default.join(LOCATION_FILE)) {
+        Ok(moved) if !moved.trim().is_empty() => {
+            Ok((PathBuf::from(moved.trim()), DataDirSource::Moved))
+        }
+        _ => Ok((default, DataDirSource::Default)),
+    }
+}
+
+fn default_dir() -> Result<PathBuf, String> {
+    dirs::data_dir()
+        .map(|dir| dir.join("clipcrab"))
+        .ok_or_else(|| "User data directory not found".to_string())
+}
+
+fn absolute(path: &Path) -> Result<PathBuf, String> {
+    std::path::absolute(path).map_err(|e| format!("Invalid path {}: {}", path.display(), e))
+}
+
+fn current() -> (PathBuf, DataDirSource) {
+    if let Some(current) = DATA_DIR.lock().unwrap().clone() {
+        return current;
+    }
+    configure_data_dir(&[]).expect("Failed to prepare data directory");
+    DATA_DIR.lock().unwrap().clone().unwrap()
+}
+
+/// Veritabanı, anahtar ve kilit dosyalarının bulunduğu uygulama veri klasörü
+pub fn data_dir() -> PathBuf {
+    current().0
+}
+
+pub fn data_dir_info() -> DataDirInfo {
+    let (path, source) = current();
+    DataDirInfo {
+        path: path.display().to_string(),
+        source,
+        movable: matches!(source, DataDirSource::Default | DataDirSource::Moved),
+    }
+}
+
+/// Varsayılan dışındaki bir klasör kullanılıyorsa soket adlarını ayıran kısa etiket;
+/// böylece farklı klasörlerle çalışan kopyalar birbirine bağlanmaz
+pub fn instance_tag() -> Option<String> {
+    let (path, source) = current();
+    if matches!(source, DataDirSource::Default | DataDirSource::Moved) {
+        return None;
+    }
+    let digest = Sha256::digest(path.to_string_lossy().as_bytes());
+    Some(digest[..4].iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+/// Bir profilin veritabanını tutarlı biçimde (yazan bağlantılar varken de) ve anahtarını kopyala
+fn copy_profile(source: &Path, target: &Path, created: &mut Vec<PathBuf>) -> Result<(), String> {
+    fs::create_dir_all(target)
+        .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+
+    let old_db = source.join(DB_FILE);
+    if old_db.exists() {
+        let new_db = target.join(DB_FILE);
+        let conn =
+            Connection::open(&old_db).map_err(|e| format!("Failed to open database: {}", e))?;
+        conn.execute("VACUUM INTO ?1", [new_db.to_string_lossy()])
+            .map_err(|e| format!("Failed to copy database: {}", e))?;
+        created.push(new_db.clone());
+
+        let copy = Connection::open(&new_db).map_err(|e| format!("Failed to open copy: {}", e))?;
+        let integrity: String = copy
+            .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to verify copy: {}", e))?;
+        if integrity != "ok" || row_count(&copy)? < row_count(&conn)? {
+            return Err(format!(
+                "The copy of {} failed verification",
+                old_db.display()
+            ));
+        }
+    }
+
+    let old_key = source.join(KEY_FILE);
+    if old_key.exists() {
+        let new_key = target.join(KEY_FILE);
+        fs::copy(&old_key, &new_key)
+            .map_err(|e| format!("Failed to copy encryption key: {}", e))?;
+        created.push(new_key);
+    }
+    Ok(())
+}
+
+/// Veritabanında yazma kilidi al; diğer süreçlerin (CLI) yazmaları bağlantı kapanana kadar bekler
+/// ve zaman aşımında reddedilir
+fn lock_database(db: &Path) -> Result<Option<Connection>, String> {
+    if !db.exists() {
+        return Ok(None);
+    }
+    let conn = Connection::open(db).map_err(|e| format!("Failed to open database: {}", e))?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))
+        .and_then(|_| conn.execute_batch("BEGIN IMMEDIATE"))
+        .map_err(|e| format!("Failed to lock {}: {}", db.display(), e))?;
+    Ok(Some(conn))
+}
+
+fn row_count(conn: &Connection) -> Result<i64, String> {
+    conn.query_row("SELECT COUNT(*) FROM clipboard_history", [], |row| {
+        row.get(0)
+    })
+    .map_err(|e| format!("Failed to count items: {}", e))
+}
+
+/// Tüm profillerin veritabanını ve anahtarını yeni klasöre kopyala, doğrula, yeni yeri kaydet ve eskilerini sil.
+/// Bunların hepsi yazma sırası tutulurken yapılır; bekleyen yazmalar taşıma bitince yeni klasöre gider.
+/// Çağıran uygulamayı hemen yeniden başlatmalıdır.
+pub fn move_data_dir(pool: &Pool, target: &Path) -> Result<PathBuf, String> {
+    let (source_dir, source) = current();
+    if !matches!(source, DataDirSource::Default | DataDirSource::Moved) {
+        return Err(format!(
+            "The data directory is fixed by --data-dir, {} or portable mode",
+            DATA_DIR_ENV
+        ));
+    }
+
+    let target = absolute(target)?;
+    if target == source_dir {
+        return Err("Data is already stored in this directory".to_string());
+    }
+    if target.starts_with(&source_dir) || source_dir.starts_with(&target) {
+        return Err("The new directory cannot contain or be inside the current one".to_string());
+    }
+    if target.join(DB_FILE).exists() || target.join(KEY_FILE).exists() {
+        return Err(format!(
+            "{} already contains ClipCrab data",
+            target.display()
+        ));
+    }
+    // Kopyalama ile eski dosyaların silinmesi arasında yapılan yazma kaybolurdu
+    let _turn = pool.turn();
+    // Her profilin veritabanı ve anahtarı aynı göreli yola kopyalanır
+    let profile_dirs = crate::profiles::relative_dirs();
+    let _locks = profile_dirs
+        .iter()
+        .map(|relative| lock_database(&source_dir.join(relative).join(DB_FILE)))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut created = Vec::new();
+    let copied = profile_dirs.iter().try_for_each(|relative| {
+        copy_profile(
+            &source_dir.join(relative),
+            &target.join(relative),
+            &mut created,
+        )
+    });
+    let copied = copied.and_then(|()| {
+        let profiles_file = source_dir.join(PROFILES_FILE);
+        if profiles_file.exists() {
+            let copy = target.join(PROFILES_FILE);
+            fs::copy(&profiles_file, &copy)
+                .map_err(|e| format!("Failed to copy profiles: {}", e))?;
+            created.push(copy);
+        }
+        Ok(())
+    });
+    if let Err(e) = copied {
+        for path in created {
+            fs::remove_file(path).ok();
+        }
+        return Err(e);
+    }
+
+    // Yeni yer kaydedildikten sonra eski dosyalar silinir; havuzdaki bağlantılar önce kapatılır
+    pool.close_idle();
+    let default = default_dir()?;
+    let location = default.join(LOCATION_FILE);
+    let source = if target == default {
+        fs::remove_file(&location).ok();
+        DataDirSource::Default
+    } else {
+        fs::create_dir_all(&default)
+            .and_then(|_| fs::write(&location, target.to_string_lossy().as_bytes()))
+            .map_err(|e| format!("Failed to record the new location: {}", e))?;
+        DataDirSource::Moved
+    };
+    // Sırada bekleyen yazmalar artık yeni klasördeki veritabanını açar
+    *DATA_DIR.lock().unwrap() = Some((target.clone(), source));
+
+    for relative in &profile_dirs {
+        let dir = source_dir.join(relative);
+        for name in [DB_FILE, "clipboard.db-wal", "clipboard.db-shm", KEY_FILE] {
+            let path = dir.join(name);
+            if path.exists() {
+                if let Err(e) = fs::remove_file(&path) {
+                    eprintln!("Failed to remove {}: {}", path.display(), e);
+                }
+            }
+        }
+        if !relative.as_os_str().is_empty() {
+            fs::remove_dir(&dir).ok();
+        }
+    }
+    fs::remove_dir(source_dir.join(crate::profiles::PROFILES_DIR)).ok();
+    fs::remove_file(source_dir.join(PROFILES_FILE)).ok();
+
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{commands, profiles};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("clipcrab-{}-{}", name, std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        dir
+    }
+
+    #[test]
+    fn flag_beats_env_and_env_beats_portable() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let dir = temp_dir("precedence");
+        let (flag, env) = (dir.join("flag"), dir.join("env"));
+
+        std::env::set_var(DATA_DIR_ENV, &env);
+        assert_eq!(
+            resolve(Some(flag.clone()), true).unwrap(),
+            (flag.clone(), DataDirSource::Flag)
+        );
+        assert_eq!(resolve(None, true).unwrap(), (env, DataDirSource::Env));
+
+        std::env::remove_var(DATA_DIR_ENV);
+        let exe_dir = std::env::current_exe()
+            .unwrap()
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        assert_eq!(
+            resolve(None, true).unwrap(),
+            (exe_dir.join(PORTABLE_DIR), DataDirSource::Portable)
+        );
+
+        // Her iki yazım biçimi de kabul edilir; değer verilmezse hata döner
+        let inline = format!("--data-dir={}", flag.display());
+        assert_eq!(
+            configure_data_dir(&[inline, "--portable".into()]).unwrap(),
+            flag
+        );
+        assert_eq!(data_dir_info().source, DataDirSource::Flag);
+        assert!(!data_dir_info().movable);
+        assert!(configure_data_dir(&["--data-dir".into()]).is_err());
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn location_file_overrides_default() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let home = temp_dir("default");
+        std::env::remove_var(DATA_DIR_ENV);
+        std::env::set_var("XDG_DATA_HOME", &home);
+
+        let default = home.join("clipcrab");
+        assert_eq!(
+            resolve(None, false).unwrap(),
+            (default.clone(), DataDirSource::Default)
+        );
+
+        let moved = home.join("moved");
+        fs::create_dir_all(&default).unwrap();
+        fs::write(
+            default.join(LOCATION_FILE),
+            format!("{}\n", moved.display()),
+        )
+        .unwrap();
+        assert_eq!(resolve(None, false).unwrap(), (moved, DataDirSource::Moved));
+
+        // Boş kayıt yok sayılır
+        fs::write(default.join(LOCATION_FILE), "").unwrap();
+        assert_eq!(
+            resolve(None, false).unwrap(),
+            (default, DataDirSource::Default)
+        );
+        std::env::remove_var("XDG_DATA_HOME");
+        fs::remove_dir_all(&home).ok();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn failed_move_rolls_back() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let home = temp_dir("move");
+        std::env::remove_var(DATA_DIR_ENV);
+        std::env::set_var("XDG_DATA_HOME", &home);
+        let source = configure_data_dir(&[]).unwrap();
+        profiles::refresh();
+
+        let pool = Pool::new();
+        pool.write(|conn| commands::add_text_item(conn, "kept", None))
+            .unwrap();
+        profiles::create("work", false).unwrap();
+
+        // Profil dosyasının kopyalanamaması için hedefte aynı adlı bir klasör bırakılır
+        let target = home.join("target");
+        let blocker = target.join(PROFILES_FILE);
+        fs::create_dir_all(&blocker).unwrap();
+        assert!(move_data_dir(&pool, &target).is_err());
+
+        assert!(!target.join(DB_FILE).exists());
+        assert!(!target.join(KEY_FILE).exists());
+        assert!(source.join(DB_FILE).exists());
+        assert!(source.join(KEY_FILE).exists());
+        assert!(!source.join(LOCATION_FILE).exists());
+        assert_eq!(data_dir(), source);
+        assert_eq!(
+            commands::get_clipboard_history_sync(&pool, None, None).len(),
+            1
+        );
+
+        // Engel kalkınca taşıma tamamlanır ve yeni yer kaydedilir
+        fs::remove_dir(&blocker).unwrap();
+        assert_eq!(move_data_dir(&pool, &target).unwrap(), target);
+        assert!(target.join(DB_FILE).exists());
+        assert!(target.join(PROFILES_FILE).is_file());
+        assert!(!source.join(DB_FILE).exists());
+        assert_eq!(
+            resolve(None, false).unwrap(),
+            (target.clone(), DataDirSource::Moved)
+        );
+
+        std::env::remove_var("XDG_DATA_HOME");
+        fs::remove_dir_all(&home).ok();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn writes_during_move_reach_the_new_directory() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+        use std::thread;
+        use std::time::Duration;
+
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let home = temp_dir("concurrent");
+        std::env::remove_var(DATA_DIR_ENV);
+        std::env::set_var("XDG_DATA_HOME", &home);
+        let source = configure_data_dir(&[]).unwrap();
+        profiles::refresh();
+        let pool = Pool::new();
+        pool.write(|conn| commands::add_text_item(conn, "before", None))
+            .unwrap();
+
+        // Taşıma sürerken sürekli yazan iş parçacığı
+        let done = Arc::new(AtomicBool::new(false));
+        let writer = thread::spawn({
+            let done = done.clone();
+            let pool = pool.clone();
+            move || {
+                let mut written = 0;
+                while !done.load(Ordering::SeqCst) {
+                    let text = format!("during {}", written);
+                    if pool
+                        .write(|conn| commands::add_text_item(conn, &text, None))
+                        .is_ok()
+                    {
+                        written += 1;
+                    }
+                }
+                written
+            }
+        });
+        thread::sleep(Duration::from_millis(50));
+
+        let target = home.join("target");
+        assert_eq!(move_data_dir(&pool, &target).unwrap(), target);
+        thread::sleep(Duration::from_millis(50));
+        done.store(true, Ordering::SeqCst);
+        let written = writer.join().unwrap();
+
+        // Her başarılı yazma yeni klasörde; eski klasörde veritabanı veya anahtar yeniden oluşmadı
+        assert!(written > 0);
+        assert_eq!(data_dir(), target);
+        assert!(!source.join(DB_FILE).exists());
+        assert!(!source.join(KEY_FILE).exists());
+        assert_eq!(
+            commands::get_clipboard_history_sync(&pool, Some(0), None).len(),
+            written + 1
+        );
+
+        std::env::remove_var("XDG_DATA_HOME");
+        fs::remove_dir_all(&home).ok();
+    }
+}
@@ -0,0 +1,27 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::Write;
+
+/// Veri klasöründeki bir kilit dosyasını almayı dene; başka bir süreç tutuyorsa `None`.
+/// Kilit, dönen dosya açık kaldığı sürece tutulur ve süreç sonlanınca işletim sistemi bırakır.
+pub fn try_lock(name: &str) -> Result<Option<File>, String> {
+    let path = super::data_dir().join(name);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return Ok(None),
+        Err(TryLockError::Error(e)) => {
+            return Err(format!("Failed to lock {}: {}", path.display(), e))
+        }
+    }
+
+    // Kilidi tutan sürecin PID'i (yalnızca bilgi amaçlı)
+    file.set_len(0).ok();
+    writeln!(file, "{}", std::process::id()).ok();
+    Ok(Some(file))
+}
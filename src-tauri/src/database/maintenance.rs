@@ -0,0 +1,250 @@
+use super::Pool;
+use crate::models::MaintenanceReport;
+use crate::security::{self, compression::COMPRESSION_THRESHOLD, ProfileCipher};
+use rusqlite::{params, Connection};
+use std::fs;
+use std::path::Path;
+use std::thread;
+
+// `PRAGMA auto_vacuum` değeri: 2 = INCREMENTAL
+const AUTO_VACUUM_INCREMENTAL: i64 = 2;
+// Raporlanan en fazla bütünlük hatası
+const MAX_INTEGRITY_ERRORS: usize = 100;
+// Mevcut büyük kayıtların sıkıştırıldığını işaretleyen ayar
+const COMPRESSION_MIGRATED_KEY: &str = "compression_migrated";
+// Sıkıştırma işlemi başına güncellenen kayıt; yazma sırası uzun süre tutulmaz
+const COMPRESSION_BATCH_SIZE: i64 = 50;
+
+/// Veritabanı ve WAL dosyasının diskteki toplam boyutu
+fn file_size() -> u64 {
+    let db_path = super::get_db_path();
+    let wal_path = db_path.with_extension("db-wal");
+    [db_path, wal_path]
+        .iter()
+        .filter_map(|path| fs::metadata(path).ok())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+fn integrity_errors(conn: &Connection) -> Result<Vec<String>, String> {
+    let mut stmt = conn
+        .prepare(&format!("PRAGMA integrity_check({})", MAX_INTEGRITY_ERRORS))
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+    let messages: Vec<String> = stmt
+        .query_map([], |row| row.get(0))
+        .map_err(|e| format!("Integrity check failed: {}", e))?
+        .filter_map(Result::ok)
+        .collect();
+    Ok(messages.into_iter().filter(|m| m != "ok").collect())
+}
+
+/// Etkin anahtarla açılamayan şifreli öğeler
+fn find_undecryptable(conn: &Connection) -> Result<Vec<i64>, String> {
+    let mut stmt = conn
+        .prepare("SELECT id, content, image_data FROM clipboard_history WHERE is_encrypted = 1")
+        .map_err(|e| format!("Prepare failed: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, Option<String>>(2)?,
+            ))
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok);
+
+    Ok(rows
+        .filter(|(_, content, image_data)| {
+            security::decrypt(content).is_err()
+                || image_data
+                    .as_deref()
+                    .filter(|data| !data.is_empty())
+                    .is_some_and(|data| security::decrypt(data).is_err())
+        })
+        .map(|(id, _, _)| id)
+        .collect())
+}
+
+/// Öğeleri karantina tablosuna taşı; silinmezler, doğru anahtar bulunursa geri alınabilir
+fn quarantine(conn: &mut Connection, ids: &[i64]) -> Result<usize, String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Transaction error: {}", e))?;
+    for id in ids {
+        tx.execute(
+            "INSERT INTO quarantine (item_id, content, content_type, category, image_data, created_at, source_app, reason, quarantined_at)
+             SELECT id, content, content_type, category, image_data, created_at, source_app, ?2, datetime('now', 'localtime')
+             FROM clipboard_history WHERE id = ?1",
+            params![id, "Cannot be decrypted with the current key"],
+        )
+        .map_err(|e| format!("Failed to quarantine item {}: {}", id, e))?;
+        tx.execute("DELETE FROM clipboard_history WHERE id = ?1", [id])
+            .map_err(|e| format!("Failed to quarantine item {}: {}", id, e))?;
+    }
+    tx.commit().map_err(|e| format!("Commit error: {}", e))?;
+    Ok(ids.len())
+}
+
+/// Bütünlüğü denetle (bozuk dizinleri yeniden oluştur), açılamayan öğeleri bul ve istenirse karantinaya al,
+/// ardından WAL'ı diske yaz, boş sayfaları geri ver ve sorgu istatistiklerini güncelle.
+/// Bozukluk giderilemezse VACUUM yapılmaz; yedekten geri yükleme önerilir.
+pub fn run_maintenance(pool: &Pool, quarantine_items: bool) -> Result<MaintenanceReport, String> {
+    pool.exclusive(|conn| {
+        let mut report = MaintenanceReport {
+            size_before: file_size(),
+            ..MaintenanceReport::default()
+        };
+
+        report.integrity_errors = integrity_errors(conn)?;
+        if !report.integrity_errors.is_empty() {
+            // Çoğu bozukluk dizinlerdedir ve tablolardan yeniden oluşturulabilir
+            conn.execute_batch("REINDEX")
+                .map_err(|e| format!("Repair failed: {}", e))?;
+            let remaining = integrity_errors(conn)?;
+            report.repaired = remaining.is_empty();
+            if !report.repaired {
+                report.integrity_errors = remaining;
+                report.size_after = file_size();
+                return Ok(report);
+            }
+        }
+        report.integrity_ok = true;
+
+        report.undecryptable = find_undecryptable(conn)?;
+        if quarantine_items && !report.undecryptable.is_empty() {
+            report.quarantined = quarantine(conn, &report.undecryptable)?;
+        }
+
+        let auto_vacuum: i64 = conn
+            .query_row("PRAGMA auto_vacuum", [], |row| row.get(0))
+            .map_err(|e| format!("Failed to read auto_vacuum: {}", e))?;
+        if auto_vacuum == AUTO_VACUUM_INCREMENTAL {
+            conn.execute_batch("PRAGMA incremental_vacuum")
+        } else {
+            // Eski veritabanları artımlı kipe ancak tam VACUUM ile geçer
+            report.full_vacuum = true;
+            conn.execute_batch("PRAGMA auto_vacuum = INCREMENTAL; VACUUM")
+        }
+        .map_err(|e| format!("Vacuum failed: {}", e))?;
+
+        conn.execute_batch("ANALYZE; PRAGMA wal_checkpoint(TRUNCATE)")
+            .map_err(|e| format!("Failed to optimize database: {}", e))?;
+
+        report.size_after = file_size();
+        Ok(report)
+    })
+}
+
+pub(super) fn existing_rows_compressed(conn: &Connection) -> bool {
+    super::get_setting::<bool>(conn, COMPRESSION_MIGRATED_KEY).unwrap_or(false)
+}
+
+/// Sıkıştırma öncesinden kalan büyük kayıtları arka planda sıkıştır.
+/// Yarıda kalırsa (ör. CLI çıkarsa) sonraki açılışta yeniden başlar.
+pub(super) fn compress_existing_rows_in_background(pool: &Pool, db_path: &Path) {
+    let pool = pool.clone();
+    let db_path = db_path.to_path_buf();
+    thread::spawn(move || match compress_existing_rows(&pool, &db_path) {
+        Ok(0) => {}
+        Ok(count) => println!("Compressed {} existing items", count),
+        Err(e) => eprintln!("Failed to compress existing items: {}", e),
+    });
+}
+
+/// Eşiği aşan şifreli içerik ve resimleri, veritabanının kendi profil anahtarıyla
+/// çözüp sıkıştırarak yeniden şifrele; etkin profil bu sırada değişebilir
+fn compress_existing_rows(pool: &Pool, db_path: &Path) -> Result<usize, String> {
+    let mut conn = pool.open(db_path);
+    let mut cipher = None;
+    let mut last_id = 0i64;
+    let mut count = 0;
+
+    loop {
+        let rows: Vec<(i64, String, Option<String>)> = {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT id, content, image_data FROM clipboard_history
+                     WHERE id > ?2 AND is_encrypted = 1 AND (
+                        (length(content) > ?1 AND substr(content, 1, 2) != 'z:')
+                        OR (length(image_data) > ?1 AND substr(image_data, 1, 2) != 'z:')
+                     )
+                     ORDER BY id LIMIT ?3",
+                )
+                .map_err(|e| format!("Prepare failed: {}", e))?;
+            let rows = stmt
+                .query_map(
+                    params![
+                        COMPRESSION_THRESHOLD as i64,
+                        last_id,
+                        COMPRESSION_BATCH_SIZE
+                    ],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+                )
+                .map_err(|e| format!("Query failed: {}", e))?
+                .filter_map(Result::ok)
+                .collect();
+            rows
+        };
+        let Some((id, _, _)) = rows.last() else {
+            break;
+        };
+        last_id = *id;
+
+        // Anahtar veritabanının yanındadır; yalnızca iş varsa yüklenir
+        if cipher.is_none() {
+            let dir = db_path.parent().unwrap_or(Path::new("."));
+            cipher = Some(ProfileCipher::load(dir)?);
+        }
+        let cipher = cipher.as_ref().unwrap();
+        let recompress = |data: &str| -> Option<String> {
+            if security::is_compressed(data) {
+                return None;
+            }
+            let encrypted = cipher.encrypt(&cipher.decrypt(data).ok()?).ok()?;
+            security::is_compressed(&encrypted).then_some(encrypted)
+        };
+
+        // Çözme ve sıkıştırma yazma sırası dışında yapılır
+        let updates: Vec<_> = rows
+            .into_iter()
+            .filter_map(|(id, content, image_data)| {
+                let new_content = recompress(&content);
+                let new_image = image_data.as_deref().and_then(recompress);
+                (new_content.is_some() || new_image.is_some()).then_some((
+                    id,
+                    content,
+                    image_data,
+                    new_content,
+                    new_image,
+                ))
+            })
+            .collect();
+
+        count += pool.write_on(&mut conn, |tx| {
+            let mut updated = 0;
+            for (id, content, image_data, new_content, new_image) in &updates {
+                // Bu sırada düzenlenen veya silinen kayda dokunulmaz
+                updated += tx
+                    .execute(
+                        "UPDATE clipboard_history SET content = ?1, image_data = ?2
+                         WHERE id = ?3 AND content = ?4 AND image_data IS ?5",
+                        params![
+                            new_content.as_ref().unwrap_or(content),
+                            new_image.as_ref().or(image_data.as_ref()),
+                            id,
+                            content,
+                            image_data
+                        ],
+                    )
+                    .map_err(|e| format!("Failed to update item {}: {}", id, e))?;
+            }
+            Ok(updated)
+        })?;
+    }
+
+    pool.write_on(&mut conn, |tx| {
+        super::set_setting(tx, COMPRESSION_MIGRATED_KEY, &true)
+    })?;
+    Ok(count)
+}
@@ -16,6 +16,10 @@ pub fn migrate_database(conn: &Connection) {
     let has_image_data = columns.iter().any(|col| col == "image_data");
     let has_pinned = columns.iter().any(|col| col == "pinned");
     let has_category = columns.iter().any(|col| col == "category");
+    let has_source_item_id = columns.iter().any(|col| col == "source_item_id");
+    let has_sensitive = columns.iter().any(|col| col == "sensitive");
+    let has_source_app = columns.iter().any(|col| col == "source_app");
+    let has_deleted_at = columns.iter().any(|col| col == "deleted_at");
 
     if !has_content_type {
         conn.execute(
@@ -48,6 +52,47 @@ pub fn migrate_database(conn: &Connection) {
         .expect("Failed to add category column");
     }
 
+    // Dönüştürülerek oluşturulan öğelerin kaynağı
+    if !has_source_item_id {
+        conn.execute(
+            "ALTER TABLE clipboard_history ADD COLUMN source_item_id INTEGER",
+            [],
+        )
+        .expect("Failed to add source_item_id column");
+    }
+
+    // Hassas öğeler önizlemelerde gizlenir
+    if !has_sensitive {
+        conn.execute(
+            "ALTER TABLE clipboard_history ADD COLUMN sensitive INTEGER DEFAULT 0",
+            [],
+        )
+        .expect("Failed to add sensitive column");
+    }
+
+    // İçeriğin kopyalandığı uygulama
+    if !has_source_app {
+        conn.execute(
+            "ALTER TABLE clipboard_history ADD COLUMN source_app TEXT",
+            [],
+        )
+        .expect("Failed to add source_app column");
+    }
+
+    // Çöp kutusu: silinme zamanı ve geri alma anahtarı (aynı anda silinenler paylaşır)
+    if !has_deleted_at {
+        conn.execute_batch(
+            "ALTER TABLE clipboard_history ADD COLUMN deleted_at TEXT;
+             ALTER TABLE clipboard_history ADD COLUMN undo_token TEXT;",
+        )
+        .expect("Failed to add trash columns");
+    }
+    conn.execute(
+        "CREATE INDEX IF NOT EXISTS idx_clipboard_deleted ON clipboard_history(deleted_at)",
+        [],
+    )
+    .ok();
+
     // Mevcut kayıtların kategorilerini güncelle (NULL veya boş olanlar için)
     update_existing_categories(conn);
 }
@@ -106,7 +151,7 @@ pub fn update_existing_categories(conn: &Connection) {
 }
 
 /// Kategori tespit fonksiyonu
-fn detect_category(content: &str) -> &'static str {
+pub fn detect_category(content: &str) -> &'static str {
     let content_lower = content.to_lowercase();
 
     // URL kontrolü
@@ -1,5 +1,22 @@
 pub mod init;
+pub mod location;
+pub mod lock;
+pub mod maintenance;
 pub mod migrate;
+pub mod pool;
+pub mod retention;
+pub mod settings;
 
-pub use init::{get_db_path, init_db, init_db_for_watcher};
-pub use migrate::migrate_database;
+pub use init::{get_db_path, init_db_for_watcher, profile_dir, upgrade_schema, SCHEMA_VERSION};
+pub use location::{configure_data_dir, data_dir, data_dir_info, DATA_DIR_ENV};
+pub use lock::try_lock;
+pub use maintenance::run_maintenance;
+pub use migrate::{detect_category, migrate_database};
+pub use pool::{Pool, PooledConnection, WriteTransaction};
+pub use retention::apply_retention;
+pub use settings::{get_setting, set_setting};
+
+/// Saklama (retention) kurallarından muaf öğeler: sabitlenenler ve bir koleksiyona ait olanlar
+pub const RETENTION_EXEMPT_CONDITION: &str = "(pinned = 1 OR id IN (
+    SELECT it.item_id FROM item_tags it JOIN collections c ON c.tag_id = it.tag_id
+))";
@@ -0,0 +1,230 @@
+use rusqlite::{Connection, Transaction, TransactionBehavior};
+use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+// Kilitli veritabanında hemen SQLITE_BUSY dönmek yerine beklenecek süre
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+// Bağlantı başına önbelleğe alınan hazır sorgu sayısı
+const STATEMENT_CACHE_CAPACITY: usize = 64;
+// Havuzda boşta tutulan en fazla bağlantı
+const MAX_IDLE: usize = 4;
+
+struct Idle {
+    path: PathBuf,
+    connections: Vec<Connection>,
+}
+
+struct Shared {
+    // Etkin profilin veritabanına açık, kullanılmayan bağlantılar
+    idle: Mutex<Idle>,
+    // Yazmalar bu kilitte sıraya girer; havuzu paylaşan izleyici, komutlar ve soket sunucusundan
+    // aynı anda tek yazan olur
+    writer: Mutex<()>,
+}
+
+/// Paylaşılan bağlantı havuzu ve yazma sırası. Arayüzde Tauri durumu olarak tutulur
+/// (`app.manage`); daemon ve CLI kendi havuzunu oluşturup izleyiciye ve soket sunucusuna verir.
+/// Kopyalar aynı havuzu gösterir.
+#[derive(Clone)]
+pub struct Pool {
+    shared: Arc<Shared>,
+}
+
+/// Havuzdan alınan bağlantı; bırakıldığında havuza geri döner
+pub struct PooledConnection {
+    conn: Option<Connection>,
+    path: PathBuf,
+    shared: Arc<Shared>,
+}
+
+impl Deref for PooledConnection {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().unwrap()
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.conn.as_mut().unwrap()
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        let Some(conn) = self.conn.take() else {
+            return;
+        };
+        // Yarım kalmış işlemi olan veya eski profile ait bağlantı kapatılır
+        if !conn.is_autocommit() {
+            return;
+        }
+        let mut idle = self.shared.idle.lock().unwrap();
+        if idle.path == self.path && idle.connections.len() < MAX_IDLE {
+            idle.connections.push(conn);
+        }
+    }
+}
+
+/// Adım adım ilerleyen yazmalar için (ör. satır satır içe aktarma) açık tutulan `IMMEDIATE` işlem;
+/// yazma sırası işlem bitene kadar tutulur, onaylanmadan bırakılırsa geri alınır
+pub struct WriteTransaction<'c> {
+    // Alan sırası önemli: işlem, sıra bırakılmadan önce kapanır
+    tx: Transaction<'c>,
+    _turn: MutexGuard<'c, ()>,
+}
+
+impl Deref for WriteTransaction<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        &self.tx
+    }
+}
+
+impl WriteTransaction<'_> {
+    pub fn commit(self) -> Result<(), String> {
+        self.tx.commit().map_err(|e| format!("Commit error: {}", e))
+    }
+}
+
+fn commit_with<T>(
+    tx: WriteTransaction<'_>,
+    f: impl FnOnce(&Connection) -> Result<T, String>,
+) -> Result<T, String> {
+    let value = f(&tx)?;
+    tx.commit()?;
+    Ok(value)
+}
+
+impl Default for Pool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pool {
+    pub fn new() -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                idle: Mutex::new(Idle {
+                    path: PathBuf::new(),
+                    connections: Vec::new(),
+                }),
+                writer: Mutex::new(()),
+            }),
+        }
+    }
+
+    /// Yeni bağlantı aç ve ayarlarını uygula
+    pub(super) fn open(&self, db_path: &Path) -> Connection {
+        let conn = Connection::open(db_path).expect("Failed to open database");
+
+        // SQLite performans optimizasyonları; artımlı VACUUM yalnızca yeni veritabanlarında hemen etkindir
+        conn.execute_batch(
+            "PRAGMA auto_vacuum = INCREMENTAL;
+             PRAGMA journal_mode = WAL;
+             PRAGMA synchronous = NORMAL;
+             PRAGMA cache_size = 10000;
+             PRAGMA temp_store = MEMORY;
+             PRAGMA foreign_keys = ON;",
+        )
+        .ok();
+        // Başka bir süreç (daemon, CLI) aynı dosyaya yazarken kilit açılana kadar bekle
+        if let Err(e) = conn.busy_timeout(BUSY_TIMEOUT) {
+            eprintln!("Failed to set busy timeout: {}", e);
+        }
+        conn.set_prepared_statement_cache_capacity(STATEMENT_CACHE_CAPACITY);
+
+        super::init::prepare_schema(self, db_path, &conn);
+        conn
+    }
+
+    /// Etkin profilin veritabanına bağlantı al; profil değiştiyse eski bağlantılar kapatılır
+    pub fn get(&self) -> PooledConnection {
+        let path = super::get_db_path();
+        let reused = {
+            let mut idle = self.shared.idle.lock().unwrap();
+            if idle.path != path {
+                idle.path = path.clone();
+                idle.connections.clear();
+            }
+            idle.connections.pop()
+        };
+
+        PooledConnection {
+            conn: Some(reused.unwrap_or_else(|| self.open(&path))),
+            path,
+            shared: self.shared.clone(),
+        }
+    }
+
+    /// Yazma sırasını al; bırakılana kadar bu havuzla başka yazma yapılmaz
+    pub(super) fn turn(&self) -> MutexGuard<'_, ()> {
+        self.shared.writer.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Yazma işlemini sırayla, tek bir `IMMEDIATE` işlem içinde çalıştır.
+    /// Hata dönerse değişiklikler geri alınır. İç içe çağrılmamalıdır.
+    pub fn write<T>(&self, f: impl FnOnce(&Connection) -> Result<T, String>) -> Result<T, String> {
+        // Sıra bağlantıdan önce alınır; veri klasörü taşınırken bekleyen yazma yeni veritabanını açar
+        let turn = self.turn();
+        let mut conn = self.get();
+        let tx = begin(&mut conn, turn)?;
+        commit_with(tx, f)
+    }
+
+    /// `write` gibi, ancak havuz dışındaki bir bağlantıyla (ör. etkin olmayan profilin veritabanı)
+    pub(super) fn write_on<T>(
+        &self,
+        conn: &mut Connection,
+        f: impl FnOnce(&Connection) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let tx = self.begin_write(conn)?;
+        commit_with(tx, f)
+    }
+
+    /// Yazma sırasını al ve `IMMEDIATE` işlem başlat. İç içe çağrılmamalıdır.
+    pub fn begin_write<'c>(
+        &'c self,
+        conn: &'c mut Connection,
+    ) -> Result<WriteTransaction<'c>, String> {
+        begin(conn, self.turn())
+    }
+
+    /// Yazma sırasını işlem açmadan tut; ör. çevrimiçi geri yükleme hedef bağlantıyı kendisi kilitler.
+    /// İç içe çağrılmamalıdır.
+    pub fn exclusive<T>(
+        &self,
+        f: impl FnOnce(&mut Connection) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let _turn = self.turn();
+        let mut conn = self.get();
+        f(&mut conn)
+    }
+
+    /// Boştaki bağlantıları kapat; veritabanı dosyası taşınmadan veya değiştirilmeden önce çağrılır
+    pub fn close_idle(&self) {
+        self.shared.idle.lock().unwrap().connections.clear();
+    }
+}
+
+fn begin<'c>(
+    conn: &'c mut Connection,
+    turn: MutexGuard<'c, ()>,
+) -> Result<WriteTransaction<'c>, String> {
+    // Sıra beklenirken veri klasörü taşındıysa bağlantı silinmiş dosyayı gösterir; yazma kaybolmasın
+    if conn
+        .path()
+        .is_some_and(|path| !path.is_empty() && !Path::new(path).exists())
+    {
+        return Err("The data directory was moved, try again".to_string());
+    }
+    let tx = conn
+        .transaction_with_behavior(TransactionBehavior::Immediate)
+        .map_err(|e| format!("Transaction error: {}", e))?;
+    Ok(WriteTransaction { tx, _turn: turn })
+}
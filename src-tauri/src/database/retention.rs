@@ -0,0 +1,53 @@
+use super::RETENTION_EXEMPT_CONDITION;
+use crate::models::Settings;
+use rusqlite::Connection;
+
+/// Süresi dolan çöp kutusu öğelerini kalıcı sil; geçmiş sınırını ve otomatik temizleme süresini aşan öğeleri
+/// çöp kutusuna taşı. Kurallar hiçbir öğeyi doğrudan silmez, yanlış bir ayar `trash_days` süresince geri alınabilir.
+/// Geçmişten çıkarılan öğe sayısını döndürür.
+pub fn apply_retention(conn: &Connection, settings: &Settings) -> Result<usize, String> {
+    let mut removed = 0;
+
+    if let Some(days) = settings.trash_days {
+        removed += conn
+            .execute(
+                "DELETE FROM clipboard_history
+                 WHERE deleted_at IS NOT NULL AND deleted_at < datetime('now', 'localtime', ?1)",
+                [format!("-{} days", days)],
+            )
+            .map_err(|e| format!("Failed to purge trash: {}", e))?;
+    }
+
+    if let Some(days) = settings.auto_clear_days {
+        removed += conn
+            .execute(
+                &format!(
+                    "UPDATE clipboard_history SET deleted_at = datetime('now', 'localtime')
+                     WHERE deleted_at IS NULL AND NOT {}
+                       AND created_at < datetime('now', 'localtime', ?1)",
+                    RETENTION_EXEMPT_CONDITION
+                ),
+                [format!("-{} days", days)],
+            )
+            .map_err(|e| format!("Failed to clear old items: {}", e))?;
+    }
+
+    // Muaf öğeler ve çöp kutusundakiler sınıra sayılmaz
+    if let Some(max_history) = settings.max_history {
+        removed += conn
+            .execute(
+                &format!(
+                    "UPDATE clipboard_history SET deleted_at = datetime('now', 'localtime')
+                     WHERE deleted_at IS NULL AND NOT {0} AND id NOT IN (
+                        SELECT id FROM clipboard_history WHERE deleted_at IS NULL AND NOT {0}
+                        ORDER BY created_at DESC, id DESC LIMIT ?1
+                     )",
+                    RETENTION_EXEMPT_CONDITION
+                ),
+                [max_history],
+            )
+            .map_err(|e| format!("Failed to apply history limit: {}", e))?;
+    }
+
+    Ok(removed)
+}
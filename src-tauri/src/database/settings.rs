@@ -0,0 +1,27 @@
+use rusqlite::{Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Ayarı oku; yoksa veya çözümlenemezse None
+pub fn get_setting<T: DeserializeOwned>(conn: &Connection, key: &str) -> Option<T> {
+    let value: Option<String> = conn
+        .query_row("SELECT value FROM settings WHERE key = ?1", [key], |row| {
+            row.get(0)
+        })
+        .optional()
+        .ok()
+        .flatten();
+
+    value.and_then(|v| serde_json::from_str(&v).ok())
+}
+
+/// Ayarı JSON olarak kaydet (varsa üzerine yazılır)
+pub fn set_setting<T: Serialize>(conn: &Connection, key: &str, value: &T) -> Result<(), String> {
+    let json = serde_json::to_string(value).map_err(|e| format!("Serialize error: {}", e))?;
+    conn.execute(
+        "INSERT INTO settings (key, value) VALUES (?1, ?2)
+         ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+        [key, json.as_str()],
+    )
+    .map_err(|e| format!("Failed to save setting: {}", e))?;
+    Ok(())
+}
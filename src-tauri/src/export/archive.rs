@@ -0,0 +1,173 @@
+use super::{is_image, ExportWriter, ItemResult};
+use crate::models::ClipboardItem;
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use std::io::{Read, Write};
+
+pub const FORMAT: &str = "clipcrab-archive";
+pub const VERSION: u32 = 1;
+
+// zstd çerçeve başlangıcı; içe aktarmada biçimi tanımak için
+pub const MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+const HEADER_FILE: &str = "clipcrab-archive.json";
+const COMPRESSION_LEVEL: i32 = 3;
+
+#[derive(Serialize, Deserialize)]
+struct ArchiveHeader {
+    format: String,
+    version: u32,
+}
+
+/// zstd ile sıkıştırılmış tar; her öğe "items/N.json", resmi varsa ardından "items/N.png"
+pub struct ArchiveWriter<W: Write> {
+    builder: Option<tar::Builder<zstd::Encoder<'static, W>>>,
+    index: usize,
+}
+
+impl<W: Write> ArchiveWriter<W> {
+    pub fn new(out: W) -> Result<Self, String> {
+        let encoder = zstd::Encoder::new(out, COMPRESSION_LEVEL)
+            .map_err(|e| format!("Failed to start compression: {}", e))?;
+        let mut writer = Self {
+            builder: Some(tar::Builder::new(encoder)),
+            index: 0,
+        };
+
+        let header = serde_json::to_vec(&ArchiveHeader {
+            format: FORMAT.to_string(),
+            version: VERSION,
+        })
+        .map_err(|e| format!("JSON failed: {}", e))?;
+        writer.append(HEADER_FILE, &header)?;
+        Ok(writer)
+    }
+
+    fn append(&mut self, name: &str, data: &[u8]) -> Result<(), String> {
+        let builder = self.builder.as_mut().ok_or("Archive is already finished")?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o600);
+        header.set_cksum();
+        builder
+            .append_data(&mut header, name, data)
+            .map_err(|e| format!("Failed to add {} to archive: {}", name, e))
+    }
+}
+
+impl<W: Write> ExportWriter for ArchiveWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        self.index += 1;
+        let stem = format!("items/{:06}", self.index);
+
+        // Resim base64 yerine ham PNG olarak ayrı girdiye yazılır
+        let image = match item.image_data.as_deref() {
+            Some(data) if is_image(item) => Some(
+                general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(|e| format!("Invalid image data for item {}: {}", item.id, e))?,
+            ),
+            _ => None,
+        };
+        let entry = ClipboardItem {
+            image_data: None,
+            ..item.clone()
+        };
+        let json = serde_json::to_vec(&entry).map_err(|e| format!("JSON failed: {}", e))?;
+
+        self.append(&format!("{}.json", stem), &json)?;
+        if let Some(png) = image {
+            self.append(&format!("{}.png", stem), &png)?;
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        let Some(builder) = self.builder.take() else {
+            return Ok(());
+        };
+        let encoder = builder
+            .into_inner()
+            .map_err(|e| format!("Failed to finish archive: {}", e))?;
+        let mut out = encoder
+            .finish()
+            .map_err(|e| format!("Failed to finish compression: {}", e))?;
+        out.flush()
+            .map_err(|e| format!("Failed to write export: {}", e))
+    }
+}
+
+/// Girdileri sırayla oku; bir öğe, resmi gelene kadar bekletilip sonra iletilir
+pub fn read_items(
+    reader: impl Read,
+    mut on_item: impl FnMut(ItemResult) -> Result<(), String>,
+) -> Result<(), String> {
+    let decoder =
+        zstd::Decoder::new(reader).map_err(|e| format!("Failed to decompress archive: {}", e))?;
+    let mut archive = tar::Archive::new(decoder);
+    let entries = archive
+        .entries()
+        .map_err(|e| format!("Invalid archive: {}", e))?;
+
+    let mut header_seen = false;
+    let mut pending: Option<(String, ItemResult)> = None;
+
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Invalid archive entry: {}", e))?;
+        let name = entry
+            .path()
+            .map_err(|e| format!("Invalid archive entry: {}", e))?
+            .to_string_lossy()
+            .to_string();
+        let mut content = Vec::new();
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Failed to read {}: {}", name, e))?;
+
+        if !header_seen {
+            let header: ArchiveHeader = serde_json::from_slice(&content)
+                .ok()
+                .filter(|_| name == HEADER_FILE)
+                .ok_or("Not a ClipCrab archive")?;
+            if header.format != FORMAT {
+                return Err("Not a ClipCrab archive".to_string());
+            }
+            if header.version > VERSION {
+                return Err(format!(
+                    "Archive version {} is newer than supported version {}",
+                    header.version, VERSION
+                ));
+            }
+            header_seen = true;
+            continue;
+        }
+
+        if let Some(stem) = name.strip_suffix(".png") {
+            match pending.as_mut() {
+                Some((pending_stem, Ok(item))) if pending_stem == stem => {
+                    item.image_data = Some(general_purpose::STANDARD.encode(&content));
+                }
+                _ => eprintln!("Skipping orphan archive entry: {}", name),
+            }
+            continue;
+        }
+
+        let Some(stem) = name.strip_suffix(".json") else {
+            eprintln!("Skipping unknown archive entry: {}", name);
+            continue;
+        };
+        if let Some((_, item)) = pending.take() {
+            on_item(item)?;
+        }
+        let item = serde_json::from_slice(&content).map_err(|e| format!("{}: {}", name, e));
+        pending = Some((stem.to_string(), item));
+    }
+
+    if let Some((_, item)) = pending.take() {
+        on_item(item)?;
+    }
+    if !header_seen {
+        return Err("Not a ClipCrab archive".to_string());
+    }
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use super::{write_error, ExportWriter};
+use crate::models::ClipboardItem;
+use std::io::Write;
+
+const HEADER: &str =
+    "id,created_at,content_type,category,pinned,sensitive,source_app,tags,content\r\n";
+
+/// RFC 4180 uyumlu CSV; her öğe bir satır
+pub struct CsvWriter<W: Write> {
+    out: W,
+    header_written: bool,
+}
+
+impl<W: Write> CsvWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            header_written: false,
+        }
+    }
+
+    fn write_header(&mut self) -> Result<(), String> {
+        if !self.header_written {
+            self.out.write_all(HEADER.as_bytes()).map_err(write_error)?;
+            self.header_written = true;
+        }
+        Ok(())
+    }
+}
+
+/// Virgül, tırnak veya satır sonu içeren alanı tırnak içine al
+fn field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+impl<W: Write> ExportWriter for CsvWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        self.write_header()?;
+        // Resimler için yalnızca "Image (WxH)" açıklaması yazılır
+        let row = [
+            item.id.to_string(),
+            field(&item.created_at),
+            field(&item.content_type),
+            field(&item.category),
+            item.pinned.to_string(),
+            item.sensitive.to_string(),
+            field(item.source_app.as_deref().unwrap_or("")),
+            field(&item.tags.join(";")),
+            field(&item.content),
+        ];
+        write!(self.out, "{}\r\n", row.join(",")).map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        // Boş dışa aktarmada da başlık satırı bulunsun
+        self.write_header()?;
+        self.out.flush().map_err(write_error)
+    }
+}
@@ -0,0 +1,55 @@
+use super::{is_image, ExportWriter};
+use crate::models::ClipboardItem;
+use base64::{engine::general_purpose, Engine as _};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Her öğe için ayrı dosya; metinler .txt, resimler .png olarak yazılır
+pub struct FolderWriter {
+    dir: PathBuf,
+}
+
+impl FolderWriter {
+    pub fn new(dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create export folder: {}", e))?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+        })
+    }
+}
+
+/// Dosya adı sıralandığında kronolojik sıra korunsun: "000042_2024-05-01_13-45-10"
+fn file_stem(item: &ClipboardItem) -> String {
+    let stamp: String = item
+        .created_at
+        .chars()
+        .map(|c| match c {
+            '0'..='9' | '-' => c,
+            ' ' | 'T' => '_',
+            _ => '-',
+        })
+        .collect();
+    format!("{:06}_{}", item.id, stamp)
+}
+
+impl ExportWriter for FolderWriter {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        let stem = file_stem(item);
+        let (name, bytes) = match item.image_data.as_deref() {
+            Some(data) if is_image(item) => (
+                format!("{}.png", stem),
+                general_purpose::STANDARD
+                    .decode(data)
+                    .map_err(|e| format!("Invalid image data for item {}: {}", item.id, e))?,
+            ),
+            _ => (format!("{}.txt", stem), item.content.clone().into_bytes()),
+        };
+
+        fs::write(self.dir.join(&name), bytes)
+            .map_err(|e| format!("Failed to write {}: {}", name, e))
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use super::{day_of, is_image, time_of, write_error, ExportWriter};
+use crate::models::ClipboardItem;
+use std::io::Write;
+
+const HEAD: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>ClipCrab History</title>
+<style>
+body { font-family: system-ui, sans-serif; max-width: 960px; margin: 2em auto; padding: 0 1em; color: #222; }
+h2 { border-bottom: 1px solid #ddd; padding-bottom: .3em; margin-top: 2em; }
+.item { border: 1px solid #e3e3e3; border-radius: 6px; padding: .8em 1em; margin: 1em 0; }
+.meta { color: #777; font-size: .85em; margin-bottom: .5em; }
+.tag { background: #eef; border-radius: 3px; padding: 0 .4em; margin-left: .3em; }
+pre { white-space: pre-wrap; word-break: break-word; margin: 0; font-family: ui-monospace, monospace; }
+img { max-width: 100%; }
+</style>
+</head>
+<body>
+<h1>ClipCrab History</h1>
+"#;
+
+const TAIL: &str = "</body>\n</html>\n";
+
+/// Resimleri data URI olarak gömen tek dosyalık HTML sayfası
+pub struct HtmlWriter<W: Write> {
+    out: W,
+    started: bool,
+    current_day: Option<String>,
+}
+
+impl<W: Write> HtmlWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            started: false,
+            current_day: None,
+        }
+    }
+
+    fn start(&mut self) -> Result<(), String> {
+        if !self.started {
+            self.out.write_all(HEAD.as_bytes()).map_err(write_error)?;
+            self.started = true;
+        }
+        Ok(())
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl<W: Write> ExportWriter for HtmlWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        self.start()?;
+        let out = &mut self.out;
+
+        let day = day_of(&item.created_at);
+        if self.current_day.as_deref() != Some(day) {
+            writeln!(out, "<h2>{}</h2>", escape(day)).map_err(write_error)?;
+            self.current_day = Some(day.to_string());
+        }
+
+        let mut meta = format!(
+            "{} · {}",
+            escape(time_of(&item.created_at)),
+            escape(&item.category)
+        );
+        if item.pinned {
+            meta.push_str(" · pinned");
+        }
+        if let Some(app) = &item.source_app {
+            meta.push_str(&format!(" · {}", escape(app)));
+        }
+        for tag in &item.tags {
+            meta.push_str(&format!("<span class=\"tag\">{}</span>", escape(tag)));
+        }
+        writeln!(
+            out,
+            "<div class=\"item\">\n<div class=\"meta\">{}</div>",
+            meta
+        )
+        .map_err(write_error)?;
+
+        match item.image_data.as_deref() {
+            Some(data) if is_image(item) => writeln!(
+                out,
+                "<img src=\"data:image/png;base64,{}\" alt=\"{}\">",
+                data,
+                escape(&item.content)
+            ),
+            _ => writeln!(out, "<pre>{}</pre>", escape(&item.content)),
+        }
+        .map_err(write_error)?;
+
+        out.write_all(b"</div>\n").map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.start()?;
+        self.out.write_all(TAIL.as_bytes()).map_err(write_error)?;
+        self.out.flush().map_err(write_error)
+    }
+}
@@ -0,0 +1,91 @@
+use super::{write_error, ExportWriter, ItemResult};
+use crate::models::ClipboardItem;
+use serde::de::{self, Deserializer as _, SeqAccess, Visitor};
+use std::fmt;
+use std::io::{Read, Write};
+
+/// İçe aktarmanın okuyabildiği JSON dizisi; öğeler geldikçe yazılır
+pub struct JsonWriter<W: Write> {
+    out: W,
+    count: usize,
+}
+
+impl<W: Write> JsonWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, count: 0 }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> ExportWriter for JsonWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        let separator = if self.count == 0 { "[\n" } else { ",\n" };
+        self.out
+            .write_all(separator.as_bytes())
+            .map_err(write_error)?;
+        serde_json::to_writer_pretty(&mut self.out, item)
+            .map_err(|e| format!("JSON failed: {}", e))?;
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        let closing = if self.count == 0 { "[]\n" } else { "\n]\n" };
+        self.out
+            .write_all(closing.as_bytes())
+            .map_err(write_error)?;
+        self.out.flush().map_err(write_error)
+    }
+}
+
+/// Dizinin elemanlarını tek tek çözüp ilet; dizinin tamamı belleğe alınmaz
+struct ItemVisitor<'a, F> {
+    on_item: F,
+    // Geri çağrının hatası (ör. iptal) JSON hatasına karışmasın diye ayrı tutulur
+    error: &'a mut Option<String>,
+}
+
+impl<'de, F> Visitor<'de> for ItemVisitor<'_, F>
+where
+    F: FnMut(ItemResult) -> Result<(), String>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of clipboard items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        while let Some(value) = seq.next_element::<serde_json::Value>()? {
+            index += 1;
+            let item = serde_json::from_value(value).map_err(|e| format!("Item {}: {}", index, e));
+            if let Err(e) = (self.on_item)(item) {
+                *self.error = Some(e);
+                return Err(de::Error::custom("aborted"));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn read_items(
+    reader: impl Read,
+    on_item: impl FnMut(ItemResult) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut error = None;
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = deserializer.deserialize_seq(ItemVisitor {
+        on_item,
+        error: &mut error,
+    });
+    if let Some(e) = error {
+        return Err(e);
+    }
+    result
+        .and_then(|_| deserializer.end())
+        .map_err(|e| format!("Invalid JSON: {}", e))
+}
@@ -0,0 +1,88 @@
+use super::{day_of, is_image, time_of, write_error, ExportWriter};
+use crate::models::ClipboardItem;
+use std::io::Write;
+
+/// Güne göre başlıklara ayrılmış Markdown belgesi
+pub struct MarkdownWriter<W: Write> {
+    out: W,
+    current_day: Option<String>,
+}
+
+impl<W: Write> MarkdownWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self {
+            out,
+            current_day: None,
+        }
+    }
+}
+
+/// İçerikteki en uzun ters tırnak dizisinden uzun kod çiti
+fn fence_for(content: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in content.chars() {
+        if c == '`' {
+            run += 1;
+            longest = longest.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    "`".repeat((longest + 1).max(3))
+}
+
+impl<W: Write> ExportWriter for MarkdownWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        let out = &mut self.out;
+        if self.current_day.is_none() {
+            out.write_all(b"# ClipCrab History\n")
+                .map_err(write_error)?;
+        }
+
+        let day = day_of(&item.created_at);
+        if self.current_day.as_deref() != Some(day) {
+            writeln!(out, "\n## {}", day).map_err(write_error)?;
+            self.current_day = Some(day.to_string());
+        }
+
+        write!(
+            out,
+            "\n### {} · {}",
+            time_of(&item.created_at),
+            item.category
+        )
+        .map_err(write_error)?;
+        if item.pinned {
+            out.write_all(" · pinned".as_bytes()).map_err(write_error)?;
+        }
+        out.write_all(b"\n\n").map_err(write_error)?;
+
+        if let Some(app) = &item.source_app {
+            writeln!(out, "- Source: {}", app).map_err(write_error)?;
+        }
+        if !item.tags.is_empty() {
+            writeln!(out, "- Tags: {}", item.tags.join(", ")).map_err(write_error)?;
+        }
+        if item.source_app.is_some() || !item.tags.is_empty() {
+            out.write_all(b"\n").map_err(write_error)?;
+        }
+
+        // Resim verisi belgeyi okunmaz yapacağından yalnızca açıklaması yazılır
+        if is_image(item) {
+            return writeln!(out, "*{}*", item.content).map_err(write_error);
+        }
+
+        let fence = fence_for(&item.content);
+        writeln!(out, "{}\n{}\n{}", fence, item.content, fence).map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        if self.current_day.is_none() {
+            self.out
+                .write_all(b"# ClipCrab History\n\nNo items.\n")
+                .map_err(write_error)?;
+        }
+        self.out.flush().map_err(write_error)
+    }
+}
@@ -0,0 +1,151 @@
+pub mod archive;
+mod csv;
+mod folder;
+mod html;
+pub mod json;
+mod markdown;
+pub mod ndjson;
+
+pub use self::csv::CsvWriter;
+pub use archive::ArchiveWriter;
+pub use folder::FolderWriter;
+pub use html::HtmlWriter;
+pub use json::JsonWriter;
+pub use markdown::MarkdownWriter;
+pub use ndjson::NdjsonWriter;
+
+use crate::models::ClipboardItem;
+use std::cell::Cell;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+/// Okunan öğe ya da öğenin neden çözümlenemediği
+pub type ItemResult = Result<ClipboardItem, String>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Ndjson,
+    Archive,
+    Csv,
+    Markdown,
+    Html,
+    Folder,
+}
+
+impl ExportFormat {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "json" => Ok(Self::Json),
+            "ndjson" | "jsonl" => Ok(Self::Ndjson),
+            "archive" => Ok(Self::Archive),
+            "csv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            "folder" | "directory" => Ok(Self::Folder),
+            other => Err(format!("Unknown export format: {}", other)),
+        }
+    }
+}
+
+/// Öğeleri tek tek alıp hedefe yazan dışa aktarıcı
+pub trait ExportWriter {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String>;
+
+    /// Kapanış içeriğini yaz ve tamponu boşalt
+    fn finish(&mut self) -> Result<(), String>;
+}
+
+/// Biçime uygun yazıcıyı oluştur; klasör dışındaki biçimler tek dosyaya yazar
+pub fn create_writer(format: ExportFormat, path: &Path) -> Result<Box<dyn ExportWriter>, String> {
+    if format == ExportFormat::Folder {
+        return Ok(Box::new(FolderWriter::new(path)?));
+    }
+
+    let file = File::create(path).map_err(|e| format!("Failed to create export file: {}", e))?;
+    create_stream_writer(format, BufWriter::new(file))
+}
+
+/// Tek dosyalık biçimleri verilen akışa (ör. stdout) yaz
+pub fn create_stream_writer<W: Write + 'static>(
+    format: ExportFormat,
+    out: W,
+) -> Result<Box<dyn ExportWriter>, String> {
+    Ok(match format {
+        ExportFormat::Json => Box::new(JsonWriter::new(out)),
+        ExportFormat::Ndjson => Box::new(NdjsonWriter::new(out)),
+        ExportFormat::Archive => Box::new(ArchiveWriter::new(out)?),
+        ExportFormat::Csv => Box::new(CsvWriter::new(out)),
+        ExportFormat::Markdown => Box::new(MarkdownWriter::new(out)),
+        ExportFormat::Html => Box::new(HtmlWriter::new(out)),
+        ExportFormat::Folder => return Err("Folder export requires a directory path".to_string()),
+    })
+}
+
+/// Okunan bayt sayısını sayan okuyucu; içe aktarma ilerlemesi bundan hesaplanır
+pub struct CountingReader<R> {
+    inner: R,
+    count: Rc<Cell<u64>>,
+}
+
+impl<R: Read> CountingReader<R> {
+    pub fn new(inner: R) -> (Self, Rc<Cell<u64>>) {
+        let count = Rc::new(Cell::new(0));
+        (
+            Self {
+                inner,
+                count: count.clone(),
+            },
+            count,
+        )
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.count.set(self.count.get() + read as u64);
+        Ok(read)
+    }
+}
+
+/// Dışa aktarılmış geçmişi oku; arşiv, JSON dizisi veya NDJSON içerikten tanınır
+pub fn read_items(
+    reader: impl Read,
+    on_item: impl FnMut(ItemResult) -> Result<(), String>,
+) -> Result<(), String> {
+    let mut reader = BufReader::new(reader);
+    let head = reader
+        .fill_buf()
+        .map_err(|e| format!("Failed to read import file: {}", e))?;
+
+    if head.starts_with(&archive::MAGIC) {
+        return archive::read_items(reader, on_item);
+    }
+    match head.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'[') => json::read_items(reader, on_item),
+        Some(b'{') => ndjson::read_items(reader, on_item),
+        None => Ok(()),
+        _ => Err("Unsupported import file format".to_string()),
+    }
+}
+
+fn write_error(e: std::io::Error) -> String {
+    format!("Failed to write export: {}", e)
+}
+
+/// "YYYY-MM-DD HH:MM:SS" biçimindeki tarihin gün kısmı
+fn day_of(created_at: &str) -> &str {
+    created_at.get(..10).unwrap_or(created_at)
+}
+
+/// Tarihin saat kısmı; beklenmeyen biçimde tarihin tamamı
+fn time_of(created_at: &str) -> &str {
+    created_at.get(11..19).unwrap_or(created_at)
+}
+
+fn is_image(item: &ClipboardItem) -> bool {
+    item.content_type == "image" && item.image_data.is_some()
+}
@@ -0,0 +1,40 @@
+use super::{write_error, ExportWriter, ItemResult};
+use crate::models::ClipboardItem;
+use std::io::{BufRead, Write};
+
+/// Satır başına bir JSON nesnesi; büyük geçmişler parça parça yazılıp okunabilir
+pub struct NdjsonWriter<W: Write> {
+    out: W,
+}
+
+impl<W: Write> NdjsonWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+}
+
+impl<W: Write> ExportWriter for NdjsonWriter<W> {
+    fn write_item(&mut self, item: &ClipboardItem) -> Result<(), String> {
+        serde_json::to_writer(&mut self.out, item).map_err(|e| format!("JSON failed: {}", e))?;
+        self.out.write_all(b"\n").map_err(write_error)
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.out.flush().map_err(write_error)
+    }
+}
+
+/// Satırları sırayla çözümle; bozuk satır tüm aktarımı durdurmaz, hata olarak iletilir
+pub fn read_items(
+    reader: impl BufRead,
+    mut on_item: impl FnMut(ItemResult) -> Result<(), String>,
+) -> Result<(), String> {
+    for (number, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| format!("Failed to read line {}: {}", number + 1, e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        on_item(serde_json::from_str(&line).map_err(|e| format!("Line {}: {}", number + 1, e)))?;
+    }
+    Ok(())
+}
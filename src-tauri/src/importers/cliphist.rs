@@ -0,0 +1,165 @@
+//! cliphist veritabanı (bbolt dosyası, "b" kovasında sıra numarası -> ham veri).
+
+use super::{image_item, is_image, now, text_item};
+use crate::models::ClipboardItem;
+use std::path::Path;
+
+const BUCKET: &[u8] = b"b";
+const MAGIC: u32 = 0xED0C_DAED;
+const PAGE_HEADER_SIZE: usize = 16;
+const ELEMENT_SIZE: usize = 16;
+const BRANCH_PAGE: u16 = 0x01;
+const LEAF_PAGE: u16 = 0x02;
+const BUCKET_LEAF_FLAG: u32 = 0x01;
+const MAX_DEPTH: usize = 64;
+
+fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn u64_at(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+fn fnv1a64(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+struct Db<'a> {
+    data: &'a [u8],
+    page_size: usize,
+}
+
+impl<'a> Db<'a> {
+    /// Sayfayı taşma sayfalarıyla birlikte döndür
+    fn page(&self, id: u64) -> Option<&'a [u8]> {
+        let start = (id as usize).checked_mul(self.page_size)?;
+        let overflow = u32_at(self.data, start + 12)? as usize;
+        let end = start.checked_add((overflow + 1) * self.page_size)?;
+        self.data.get(start..end.min(self.data.len()))
+    }
+
+    /// Sayfadaki (ve alt sayfalardaki) yaprak öğelerini gez: (bayraklar, anahtar, değer)
+    fn walk(
+        &self,
+        page: &'a [u8],
+        depth: usize,
+        visit: &mut dyn FnMut(u32, &'a [u8], &'a [u8]),
+    ) -> Result<(), String> {
+        if depth > MAX_DEPTH {
+            return Err("cliphist database is corrupted".to_string());
+        }
+        let corrupted = || "cliphist database is corrupted".to_string();
+        let flags = u16_at(page, 8).ok_or_else(corrupted)?;
+        let count = u16_at(page, 10).ok_or_else(corrupted)? as usize;
+
+        for index in 0..count {
+            let element = PAGE_HEADER_SIZE + index * ELEMENT_SIZE;
+            if flags & BRANCH_PAGE != 0 {
+                let child = u64_at(page, element + 8).ok_or_else(corrupted)?;
+                let child = self.page(child).ok_or_else(corrupted)?;
+                self.walk(child, depth + 1, visit)?;
+            } else if flags & LEAF_PAGE != 0 {
+                let element_flags = u32_at(page, element).ok_or_else(corrupted)?;
+                let pos = u32_at(page, element + 4).ok_or_else(corrupted)? as usize;
+                let key_size = u32_at(page, element + 8).ok_or_else(corrupted)? as usize;
+                let value_size = u32_at(page, element + 12).ok_or_else(corrupted)? as usize;
+                // Konum, öğe başlığının kendi adresine göredir
+                let key_start = element + pos;
+                let key = page
+                    .get(key_start..key_start + key_size)
+                    .ok_or_else(corrupted)?;
+                let value = page
+                    .get(key_start + key_size..key_start + key_size + value_size)
+                    .ok_or_else(corrupted)?;
+                visit(element_flags, key, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Kovanın kök sayfası (küçük kovalar değerin içinde satır içi saklanır)
+    fn bucket_page(&self, value: &'a [u8]) -> Option<&'a [u8]> {
+        match u64_at(value, 0)? {
+            0 => value.get(16..),
+            root => self.page(root),
+        }
+    }
+}
+
+/// Geçerli meta sayfalarından en yüksek işlem numaralısının kök kovası
+fn root_page_id(data: &[u8]) -> Result<(usize, u64), String> {
+    let page_size = u32_at(data, PAGE_HEADER_SIZE + 8)
+        .filter(|size| *size >= 512)
+        .ok_or_else(|| "Not a cliphist database".to_string())? as usize;
+
+    [0, page_size]
+        .iter()
+        .filter_map(|&start| {
+            let meta = data.get(start + PAGE_HEADER_SIZE..start + PAGE_HEADER_SIZE + 64)?;
+            let valid = u32_at(meta, 0)? == MAGIC && u64_at(meta, 56)? == fnv1a64(&meta[..56]);
+            valid.then(|| (u64_at(meta, 48).unwrap_or(0), u64_at(meta, 16).unwrap_or(0)))
+        })
+        .max_by_key(|(txid, _)| *txid)
+        .map(|(_, root)| (page_size, root))
+        .ok_or_else(|| "Not a cliphist database".to_string())
+}
+
+pub fn parse(path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    let data =
+        std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let (page_size, root) = root_page_id(&data)?;
+    let db = Db {
+        data: &data,
+        page_size,
+    };
+
+    let root_page = db
+        .page(root)
+        .ok_or_else(|| "cliphist database is corrupted".to_string())?;
+    let mut bucket = None;
+    db.walk(root_page, 0, &mut |flags, key, value| {
+        if flags & BUCKET_LEAF_FLAG != 0 && key == BUCKET {
+            bucket = Some(value);
+        }
+    })?;
+    let bucket = bucket.ok_or_else(|| "cliphist history bucket not found".to_string())?;
+    let bucket_page = db
+        .bucket_page(bucket)
+        .ok_or_else(|| "cliphist database is corrupted".to_string())?;
+
+    // Anahtarlar büyük endian sıra numarası olduğundan sayfa sırası kronolojiktir
+    let mut entries = Vec::new();
+    db.walk(bucket_page, 0, &mut |flags, _, value| {
+        if flags & BUCKET_LEAF_FLAG == 0 {
+            entries.push(value);
+        }
+    })?;
+
+    let created_at = now();
+    let items = entries
+        .into_iter()
+        .filter_map(|value| {
+            if is_image(value) {
+                image_item(value, created_at.clone())
+            } else {
+                String::from_utf8(value.to_vec())
+                    .ok()
+                    .map(|text| text_item(text, created_at.clone()))
+            }
+        })
+        .collect();
+    Ok(items)
+}
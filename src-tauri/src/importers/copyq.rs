@@ -0,0 +1,188 @@
+//! CopyQ sekme dosyaları (`copyq_tab_*.dat`, Qt QDataStream biçimi).
+//! Şifreli ve eşitlenen (itemsync) sekmeler desteklenmez.
+
+use super::{image_item, now, text_item};
+use crate::models::ClipboardItem;
+use flate2::read::ZlibDecoder;
+use std::io::Read;
+use std::path::Path;
+
+/// QDataStream okuyucu (büyük endian)
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or_else(|| "Unexpected end of CopyQ data".to_string())?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        Ok(u32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        Ok(self.u32()? as i32)
+    }
+
+    fn bool(&mut self) -> Result<bool, String> {
+        Ok(self.take(1)?[0] != 0)
+    }
+
+    /// QByteArray: uzunluk + veri (0xFFFFFFFF boş dizi)
+    fn bytes(&mut self) -> Result<&'a [u8], String> {
+        match self.u32()? {
+            u32::MAX => Ok(&[]),
+            len => self.take(len as usize),
+        }
+    }
+
+    /// QString: bayt uzunluğu + UTF-16BE
+    fn string(&mut self) -> Result<String, String> {
+        let bytes = self.bytes()?;
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+        Ok(String::from_utf16_lossy(&units))
+    }
+}
+
+/// qCompress çıktısını aç (4 bayt boyut + zlib)
+fn q_uncompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    if data.len() < 4 {
+        return Ok(Vec::new());
+    }
+    let mut out = Vec::new();
+    ZlibDecoder::new(&data[4..])
+        .read_to_end(&mut out)
+        .map_err(|e| format!("Failed to decompress CopyQ data: {}", e))?;
+    Ok(out)
+}
+
+enum Format {
+    Text,
+    Image,
+}
+
+/// MIME türünü tanı; CopyQ ortak önekleri tek karakterle kısaltır ("0" tam ad demektir)
+fn classify_mime(mime: &str) -> Option<Format> {
+    let mime = mime.trim_start_matches(|c: char| c.is_ascii_digit());
+    let subtype = mime.rsplit('/').next().unwrap_or(mime);
+    let subtype = subtype.split(';').next().unwrap_or(subtype);
+
+    if subtype == "plain" && (mime.starts_with("text/") || !mime.contains('/')) {
+        return Some(Format::Text);
+    }
+    if matches!(subtype, "png" | "jpeg" | "jpg" | "bmp" | "gif" | "webp")
+        && (mime.starts_with("image/") || !mime.contains('/'))
+    {
+        return Some(Format::Image);
+    }
+    None
+}
+
+/// Tek öğenin MIME -> veri eşlemesini oku
+fn read_item(reader: &mut Reader) -> Result<Vec<(String, Vec<u8>)>, String> {
+    // Eski sürümler doğrudan öğe sayısını yazar, yeniler önce negatif sürüm numarası
+    let version = reader.i32()?;
+    let (size, version) = if version >= 0 {
+        (version, 0)
+    } else {
+        (reader.i32()?, version)
+    };
+
+    let mut entries = Vec::new();
+    for _ in 0..size.max(0) {
+        let mime = reader.string()?;
+        let data = match version {
+            -2 => {
+                let compressed = reader.bool()?;
+                let bytes = reader.bytes()?;
+                if compressed {
+                    q_uncompress(bytes)?
+                } else {
+                    bytes.to_vec()
+                }
+            }
+            -1 | 0 => q_uncompress(reader.bytes()?)?,
+            other => return Err(format!("Unsupported CopyQ item version {}", other)),
+        };
+        entries.push((mime, data));
+    }
+    Ok(entries)
+}
+
+fn parse_tab(data: &[u8], created_at: &str) -> Result<Vec<ClipboardItem>, String> {
+    let mut reader = Reader { data, pos: 0 };
+    let count = reader.i32()?;
+    if !(0..=10_000_000).contains(&count) {
+        return Err("Unsupported CopyQ tab (encrypted or synchronized)".to_string());
+    }
+
+    let mut items = Vec::new();
+    for _ in 0..count {
+        let entries = read_item(&mut reader)?;
+
+        // Metin varsa metin olarak, yoksa ilk resim olarak alınır
+        let text = entries
+            .iter()
+            .find(|(mime, _)| matches!(classify_mime(mime), Some(Format::Text)))
+            .map(|(_, data)| String::from_utf8_lossy(data).to_string());
+        let item = match text {
+            Some(text) => Some(text_item(text, created_at.to_string())),
+            None => entries
+                .iter()
+                .filter(|(mime, _)| matches!(classify_mime(mime), Some(Format::Image)))
+                .find_map(|(_, data)| image_item(data, created_at.to_string())),
+        };
+        items.extend(item);
+    }
+
+    // Sekmede en yeni öğe en üstte
+    items.reverse();
+    Ok(items)
+}
+
+fn read_tab(file: &Path, created_at: &str) -> Result<Vec<ClipboardItem>, String> {
+    let data =
+        std::fs::read(file).map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    parse_tab(&data, created_at)
+}
+
+pub fn parse(path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    let created_at = now();
+    if !path.is_dir() {
+        return read_tab(path, &created_at);
+    }
+
+    // Klasör verilirse tüm sekme dosyaları okunur, okunamayan sekmeler atlanır
+    let mut files: Vec<_> = std::fs::read_dir(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|file| {
+            file.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("copyq_tab_") && name.ends_with(".dat"))
+        })
+        .collect();
+    files.sort();
+
+    let mut items = Vec::new();
+    for file in files {
+        match read_tab(&file, &created_at) {
+            Ok(tab_items) => items.extend(tab_items),
+            Err(e) => eprintln!("Skipping CopyQ tab {}: {}", file.display(), e),
+        }
+    }
+    Ok(items)
+}
@@ -0,0 +1,54 @@
+//! Diodon geçmişi (Zeitgeist `activity.sqlite` içindeki Diodon olayları).
+
+use super::{format_unix_time, image_item, text_item};
+use crate::models::ClipboardItem;
+use rusqlite::{Connection, OpenFlags};
+use std::path::Path;
+
+const DIODON_ACTOR: &str = "application://diodon.desktop";
+
+pub fn parse(path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Failed to open Zeitgeist database: {}", e))?;
+
+    // Metin öğeleri konu metninde, resimler PNG olarak olay yükünde saklanır
+    let mut stmt = conn
+        .prepare(
+            "SELECT e.timestamp, t.value, m.value, p.value
+             FROM event e
+             JOIN actor a ON a.id = e.actor
+             LEFT JOIN text t ON t.id = e.subj_text
+             LEFT JOIN mimetype m ON m.id = e.subj_mimetype
+             LEFT JOIN payload p ON p.id = e.payload
+             WHERE a.value = ?1
+             ORDER BY e.timestamp ASC, e.id ASC",
+        )
+        .map_err(|e| format!("Not a Zeitgeist database: {}", e))?;
+
+    let rows = stmt
+        .query_map([DIODON_ACTOR], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, Option<String>>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<Vec<u8>>>(3)?,
+            ))
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok);
+
+    let items = rows
+        .filter_map(|(timestamp, text, mimetype, payload)| {
+            // Zeitgeist zamanları milisaniye cinsindendir
+            let created_at = format_unix_time(timestamp / 1000);
+            let is_image = mimetype.as_deref().is_some_and(|m| m.starts_with("image/"));
+            match (is_image, payload) {
+                (true, Some(payload)) => image_item(&payload, created_at),
+                _ => text
+                    .filter(|text| !text.trim().is_empty())
+                    .map(|text| text_item(text, created_at)),
+            }
+        })
+        .collect();
+    Ok(items)
+}
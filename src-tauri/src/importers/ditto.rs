@@ -0,0 +1,138 @@
+//! Ditto veritabanı (`Ditto.db`, Windows pano biçimleri `Data` tablosunda).
+
+use super::{format_unix_time, image_item, text_item};
+use crate::models::ClipboardItem;
+use rusqlite::{Connection, OpenFlags};
+use std::path::Path;
+
+const BMP_FILE_HEADER_SIZE: usize = 14;
+const BI_BITFIELDS: u32 = 3;
+
+/// CF_UNICODETEXT: sonu NUL ile biten UTF-16LE
+fn decode_unicode_text(data: &[u8]) -> String {
+    let units: Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|unit| *unit != 0)
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// CF_TEXT: sonu NUL ile biten ANSI metin
+fn decode_ansi_text(data: &[u8]) -> String {
+    let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+    String::from_utf8_lossy(&data[..end]).to_string()
+}
+
+/// CF_DIB verisinin önüne BMP dosya başlığı ekle
+fn dib_to_bmp(dib: &[u8]) -> Option<Vec<u8>> {
+    let header_size = u32::from_le_bytes(dib.get(0..4)?.try_into().ok()?) as usize;
+    let bit_count = u16::from_le_bytes(dib.get(14..16)?.try_into().ok()?);
+    let compression = u32::from_le_bytes(dib.get(16..20)?.try_into().ok()?);
+    let colors_used = u32::from_le_bytes(dib.get(32..36)?.try_into().ok()?) as usize;
+
+    let masks = if compression == BI_BITFIELDS && header_size == 40 {
+        12
+    } else {
+        0
+    };
+    let palette = match colors_used {
+        0 if bit_count <= 8 => (1usize << bit_count) * 4,
+        n => n * 4,
+    };
+    let offset = BMP_FILE_HEADER_SIZE + header_size + masks + palette;
+
+    let mut bmp = Vec::with_capacity(BMP_FILE_HEADER_SIZE + dib.len());
+    bmp.extend_from_slice(b"BM");
+    bmp.extend_from_slice(&((BMP_FILE_HEADER_SIZE + dib.len()) as u32).to_le_bytes());
+    bmp.extend_from_slice(&[0; 4]);
+    bmp.extend_from_slice(&(offset as u32).to_le_bytes());
+    bmp.extend_from_slice(dib);
+    Some(bmp)
+}
+
+/// Bir Ditto girdisinin biçimlerinden en uygununu seç
+fn to_item(
+    formats: &[(String, Vec<u8>)],
+    preview: Option<String>,
+    created_at: String,
+) -> Option<ClipboardItem> {
+    let find = |name: &str| {
+        formats
+            .iter()
+            .find(|(format, _)| format.eq_ignore_ascii_case(name))
+            .map(|(_, data)| data.as_slice())
+    };
+
+    if let Some(item) = find("PNG").and_then(|png| image_item(png, created_at.clone())) {
+        return Some(item);
+    }
+    if let Some(item) = find("CF_DIB")
+        .and_then(dib_to_bmp)
+        .and_then(|bmp| image_item(&bmp, created_at.clone()))
+    {
+        return Some(item);
+    }
+
+    find("CF_UNICODETEXT")
+        .map(decode_unicode_text)
+        .or_else(|| find("CF_TEXT").map(decode_ansi_text))
+        .or(preview)
+        .filter(|text| !text.trim().is_empty())
+        .map(|text| text_item(text, created_at))
+}
+
+pub fn parse(path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .map_err(|e| format!("Failed to open Ditto database: {}", e))?;
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.lID, m.lDate, m.mText, d.strClipBoardFormat, d.ooData
+             FROM Main m
+             LEFT JOIN Data d ON d.lParentID = m.lID
+             WHERE m.bIsGroup = 0
+             ORDER BY m.lDate ASC, m.lID ASC",
+        )
+        .map_err(|e| format!("Not a Ditto database: {}", e))?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, i64>(0)?,
+                row.get::<_, i64>(1)?,
+                row.get::<_, Option<String>>(2)?,
+                row.get::<_, Option<String>>(3)?,
+                row.get::<_, Option<Vec<u8>>>(4)?,
+            ))
+        })
+        .map_err(|e| format!("Query failed: {}", e))?
+        .filter_map(Result::ok);
+
+    // Satırlar girdi başına bir biçim içerir; aynı girdinin biçimleri gruplanır
+    let mut items = Vec::new();
+    let mut current: Option<(i64, i64, Option<String>)> = None;
+    let mut formats: Vec<(String, Vec<u8>)> = Vec::new();
+
+    for (id, date, preview, format, data) in rows {
+        if current
+            .as_ref()
+            .is_some_and(|(current_id, _, _)| *current_id != id)
+        {
+            let (_, date, preview) = current.take().unwrap();
+            items.extend(to_item(&formats, preview, format_unix_time(date)));
+            formats.clear();
+        }
+        if current.is_none() {
+            current = Some((id, date, preview));
+        }
+        if let (Some(format), Some(data)) = (format, data) {
+            formats.push((format, data));
+        }
+    }
+    if let Some((_, date, preview)) = current {
+        items.extend(to_item(&formats, preview, format_unix_time(date)));
+    }
+
+    Ok(items)
+}
@@ -0,0 +1,137 @@
+//! GPaste geçmişi (`history.xml`).
+
+use super::{format_unix_time, image_item, now, text_item};
+use crate::models::ClipboardItem;
+use std::path::Path;
+
+/// XML varlıklarını çöz (&amp;, &lt;, &#NN; ...)
+fn unescape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Etiketin özniteliklerini oku (`<item kind="Text" date="...">`)
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let needle = format!(" {}=\"", name);
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')? + start;
+    Some(unescape(&tag[start..end]))
+}
+
+/// `<value>` içeriği; GPaste "]]>" içeren metni birden fazla CDATA bölümüne ayırır
+fn value_text(inner: &str) -> String {
+    let mut out = String::new();
+    let mut rest = inner;
+    while !rest.is_empty() {
+        if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+            let end = cdata.find("]]>").unwrap_or(cdata.len());
+            out.push_str(&cdata[..end]);
+            rest = cdata.get(end + 3..).unwrap_or("");
+        } else {
+            let end = rest.find("<![CDATA[").unwrap_or(rest.len());
+            out.push_str(&unescape(&rest[..end]));
+            rest = &rest[end..];
+        }
+    }
+    out
+}
+
+/// GPaste sürümüne göre saniye, milisaniye veya mikrosaniye olabilir
+fn parse_date(value: &str) -> Option<String> {
+    let mut number: i64 = value.trim().parse().ok()?;
+    while number > 100_000_000_000 {
+        number /= 1000;
+    }
+    Some(format_unix_time(number))
+}
+
+pub fn parse(path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    let xml = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if !xml.contains("<history") {
+        return Err("Not a GPaste history file".to_string());
+    }
+
+    let fallback_date = now();
+    let mut items = Vec::new();
+    let mut rest = xml.as_str();
+
+    while let Some(start) = rest.find("<item ") {
+        rest = &rest[start..];
+        let Some(tag_end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[..tag_end];
+        if tag.ends_with('/') {
+            rest = &rest[tag_end + 1..];
+            continue;
+        }
+        let Some(item_end) = rest.find("</item>") else {
+            break;
+        };
+        let body = &rest[tag_end + 1..item_end];
+        rest = &rest[item_end + "</item>".len()..];
+
+        let value = match (body.find("<value>"), body.find("</value>")) {
+            (Some(open), Some(close)) if open < close => value_text(&body[open + 7..close]),
+            _ => continue,
+        };
+        let created_at = attribute(tag, "date")
+            .and_then(|date| parse_date(&date))
+            .unwrap_or_else(|| fallback_date.clone());
+
+        match attribute(tag, "kind").as_deref() {
+            Some("Text") | Some("Uris") if !value.trim().is_empty() => {
+                items.push(text_item(value, created_at));
+            }
+            // Parola öğeleri hassas olarak işaretlenir
+            Some("Password") => {
+                let mut item = text_item(value, created_at);
+                item.sensitive = true;
+                items.push(item);
+            }
+            // Resim öğelerinin değeri, GPaste'in sakladığı dosyanın yoludur
+            Some("Image") => match std::fs::read(value.trim()) {
+                Ok(bytes) => items.extend(image_item(&bytes, created_at)),
+                Err(e) => eprintln!("Skipping GPaste image {}: {}", value.trim(), e),
+            },
+            _ => {}
+        }
+    }
+
+    // GPaste en yeni öğeyi başa yazar
+    items.reverse();
+    Ok(items)
+}
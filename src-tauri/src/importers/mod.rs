@@ -0,0 +1,127 @@
+mod cliphist;
+mod copyq;
+mod diodon;
+mod ditto;
+mod gpaste;
+
+use crate::models::ClipboardItem;
+use base64::{engine::general_purpose, Engine as _};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Desteklenen kaynaklar: (kimlik, görünen ad)
+pub const SOURCES: &[(&str, &str)] = &[
+    ("copyq", "CopyQ"),
+    ("gpaste", "GPaste"),
+    ("cliphist", "cliphist"),
+    ("diodon", "Diodon"),
+    ("ditto", "Ditto"),
+];
+
+/// Kaynağın varsayılan geçmiş dosyası (CopyQ için sekme dosyalarının klasörü)
+pub fn default_path(source: &str) -> Option<PathBuf> {
+    match source {
+        "copyq" => dirs::config_dir().map(|dir| dir.join("copyq")),
+        "gpaste" => dirs::data_dir().map(|dir| dir.join("gpaste").join("history.xml")),
+        "cliphist" => dirs::cache_dir().map(|dir| dir.join("cliphist").join("db")),
+        "diodon" => dirs::data_dir().map(|dir| dir.join("zeitgeist").join("activity.sqlite")),
+        "ditto" => dirs::config_dir().map(|dir| dir.join("Ditto").join("Ditto.db")),
+        _ => None,
+    }
+}
+
+/// Kaynağın geçmişini eskiden yeniye sıralı öğelere dönüştür
+pub fn parse(source: &str, path: &Path) -> Result<Vec<ClipboardItem>, String> {
+    if !path.exists() {
+        return Err(format!("{} not found", path.display()));
+    }
+
+    match source {
+        "copyq" => copyq::parse(path),
+        "gpaste" => gpaste::parse(path),
+        "cliphist" => cliphist::parse(path),
+        "diodon" => diodon::parse(path),
+        "ditto" => ditto::parse(path),
+        other => Err(format!("Unknown import source '{}'", other)),
+    }
+}
+
+fn new_item(content_type: &str, content: String, created_at: String) -> ClipboardItem {
+    ClipboardItem {
+        id: 0,
+        content,
+        content_type: content_type.to_string(),
+        category: String::new(), // Eklenirken içerikten belirlenir
+        image_data: None,
+        created_at,
+        pinned: false,
+        sensitive: false,
+        source_app: None,
+        tags: Vec::new(),
+    }
+}
+
+fn text_item(text: String, created_at: String) -> ClipboardItem {
+    new_item("text", text, created_at)
+}
+
+/// Herhangi bir desteklenen resim biçimini PNG öğesine çevir
+fn image_item(bytes: &[u8], created_at: String) -> Option<ClipboardItem> {
+    let image = image::load_from_memory(bytes).ok()?;
+    let mut png = Vec::new();
+    image
+        .write_to(
+            &mut std::io::Cursor::new(&mut png),
+            image::ImageOutputFormat::Png,
+        )
+        .ok()?;
+
+    let mut item = new_item(
+        "image",
+        format!("Image ({}x{})", image.width(), image.height()),
+        created_at,
+    );
+    item.category = "image".to_string();
+    item.image_data = Some(general_purpose::STANDARD.encode(png));
+    Some(item)
+}
+
+fn is_image(bytes: &[u8]) -> bool {
+    image::guess_format(bytes).is_ok()
+}
+
+/// Unix zamanını UTC ISO-8601 biçimine çevir (içe aktarma yerel saate dönüştürür)
+fn format_unix_time(secs: i64) -> String {
+    let days = secs.div_euclid(86_400);
+    let time = secs.rem_euclid(86_400);
+
+    // Gün sayısından takvim tarihi (proleptik Gregoryen)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// Zaman bilgisi olmayan kaynaklar için içe aktarma anı
+fn now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+    format_unix_time(secs)
+}
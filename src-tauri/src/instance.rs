@@ -0,0 +1,216 @@
+use crate::database;
+use lazy_static::lazy_static;
+use std::fs::File;
+use std::sync::Mutex;
+use tauri::{Emitter, Manager};
+
+// Arayüzü çalıştıran sürecin tuttuğu kilit; aynı veri klasöründe yalnızca bir pencere açılır
+const LOCK_FILE: &str = "instance.lock";
+// İkinci açılışın argümanlarını ileten JSON-RPC yöntemi
+pub const ACTIVATE_METHOD: &str = "activate";
+
+lazy_static! {
+    static ref INSTANCE_LOCK: Mutex<Option<File>> = Mutex::new(None);
+    // Ön yüz yüklenmeden istenen arama; ön yüz açılışta alır
+    static ref PENDING_SEARCH: Mutex<Option<String>> = Mutex::new(None);
+}
+
+/// Arayüzün anladığı açılış argümanları
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LaunchArgs {
+    pub show: bool,
+    pub minimized: bool,
+    pub search: Option<String>,
+}
+
+impl LaunchArgs {
+    /// Bilinmeyen argümanlar yok sayılır
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Self {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--show" => parsed.show = true,
+                "--minimized" => parsed.minimized = true,
+                "--search" => parsed.search = args.next(),
+                _ => {
+                    if let Some(query) = arg.strip_prefix("--search=") {
+                        parsed.search = Some(query.to_string());
+                    }
+                }
+            }
+        }
+        parsed
+    }
+}
+
+/// Örnek kilidini al; başka bir arayüz çalışıyorsa argümanları ona iletip `false` döndür
+pub fn acquire_or_forward(args: &[String]) -> bool {
+    match database::try_lock(LOCK_FILE) {
+        Ok(Some(lock)) => {
+            *INSTANCE_LOCK.lock().unwrap() = Some(lock);
+            true
+        }
+        Ok(None) => {
+            match forward(args) {
+                Ok(()) => println!("ClipCrab is already running, arguments forwarded"),
+                Err(e) => eprintln!("ClipCrab is already running: {}", e),
+            }
+            false
+        }
+        Err(e) => {
+            // Kilit kullanılamıyorsa açılışı engelleme
+            eprintln!("{}", e);
+            true
+        }
+    }
+}
+
+/// İlk açılışın argümanlarını uygula; pencere henüz ön yüzü yüklememiştir
+pub fn apply_startup(app_handle: &tauri::AppHandle, args: &LaunchArgs) {
+    if let Some(query) = &args.search {
+        *PENDING_SEARCH.lock().unwrap() = Some(query.clone());
+    } else if args.minimized && !args.show {
+        if let Some(window) = app_handle.get_webview_window("main") {
+            let _ = window.hide();
+        }
+    }
+}
+
+/// Açılışta istenen aramayı bir kez döndür
+pub fn take_pending_search() -> Option<String> {
+    PENDING_SEARCH.lock().unwrap().take()
+}
+
+/// Sonraki bir açılıştan gelen argümanları çalışan arayüze uygula
+fn activate(app_handle: &tauri::AppHandle, args: &LaunchArgs) {
+    // Otomatik başlatma zaten çalışan pencereyi öne getirmemeli
+    if args.minimized && !args.show && args.search.is_none() {
+        return;
+    }
+
+    if let Some(window) = app_handle.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    if let Some(query) = &args.search {
+        if let Err(e) = app_handle.emit("search-requested", query) {
+            eprintln!("Failed to send search request: {}", e);
+        }
+    }
+}
+
+/// Sonraki açılışların argümanlarını dinle (yalnızca Unix)
+pub fn listen(app_handle: tauri::AppHandle) {
+    #[cfg(unix)]
+    if let Err(e) = server::start(app_handle) {
+        eprintln!("Instance forwarding disabled: {}", e);
+    }
+
+    #[cfg(not(unix))]
+    let _ = app_handle;
+}
+
+#[cfg(unix)]
+fn forward(args: &[String]) -> Result<(), String> {
+    server::forward(args)
+}
+
+#[cfg(not(unix))]
+fn forward(_args: &[String]) -> Result<(), String> {
+    Err("argument forwarding is not supported on this platform".to_string())
+}
+
+#[cfg(unix)]
+mod server {
+    use super::{activate, LaunchArgs, ACTIVATE_METHOD};
+    use crate::ipc::{self, Request, Response};
+    use serde_json::{json, Value};
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+    use std::thread;
+    use std::time::Duration;
+
+    // Çalışan örnek yanıt vermezse ikinci açılış beklemeden çıkar
+    const FORWARD_TIMEOUT: Duration = Duration::from_secs(3);
+
+    pub fn start(app_handle: tauri::AppHandle) -> Result<(), String> {
+        // Örnek kilidini tutan tek süreç dinler
+        let listener = ipc::bind_private(&ipc::instance_socket_path())?;
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => {
+                        if let Err(e) = handle_connection(stream, &app_handle) {
+                            eprintln!("Instance forwarding failed: {}", e);
+                        }
+                    }
+                    Err(e) => eprintln!("Instance connection failed: {}", e),
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    fn handle_connection(stream: UnixStream, app_handle: &tauri::AppHandle) -> Result<(), String> {
+        stream.set_read_timeout(Some(FORWARD_TIMEOUT)).ok();
+        let mut line = String::new();
+        BufReader::new(&stream)
+            .read_line(&mut line)
+            .map_err(|e| e.to_string())?;
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) if request.method == ACTIVATE_METHOD => {
+                let args: Vec<String> = request
+                    .params
+                    .get("args")
+                    .and_then(|args| serde_json::from_value(args.clone()).ok())
+                    .unwrap_or_default();
+                activate(app_handle, &LaunchArgs::parse(args));
+                Response::ok(request.id.unwrap_or(Value::Null), json!(true))
+            }
+            Ok(request) => Response::error(
+                request.id.unwrap_or(Value::Null),
+                ipc::METHOD_NOT_FOUND,
+                format!("Unknown method: {}", request.method),
+            ),
+            Err(e) => Response::error(Value::Null, ipc::PARSE_ERROR, e.to_string()),
+        };
+
+        let mut line = serde_json::to_vec(&response).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+        (&stream).write_all(&line).map_err(|e| e.to_string())
+    }
+
+    pub fn forward(args: &[String]) -> Result<(), String> {
+        let path = ipc::instance_socket_path();
+        let stream = UnixStream::connect(&path)
+            .map_err(|e| format!("Failed to connect to {}: {}", path.display(), e))?;
+        stream.set_read_timeout(Some(FORWARD_TIMEOUT)).ok();
+        stream.set_write_timeout(Some(FORWARD_TIMEOUT)).ok();
+
+        let request = Request {
+            jsonrpc: "2.0".to_string(),
+            method: ACTIVATE_METHOD.to_string(),
+            params: json!({ "args": args }),
+            id: Some(json!(1)),
+        };
+        let mut line = serde_json::to_vec(&request).map_err(|e| e.to_string())?;
+        line.push(b'\n');
+        (&stream).write_all(&line).map_err(|e| e.to_string())?;
+
+        let mut reply = String::new();
+        BufReader::new(&stream)
+            .read_line(&mut reply)
+            .map_err(|e| format!("No reply from running instance: {}", e))?;
+        let response: Response =
+            serde_json::from_str(&reply).map_err(|e| format!("Invalid reply: {}", e))?;
+        match response.error {
+            Some(error) => Err(error.message),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+mod protocol;
+#[cfg(unix)]
+mod server;
+
+pub use protocol::{Request, Response, RpcError, METHOD_NOT_FOUND, PARSE_ERROR};
+
+use crate::database::Pool;
+use crate::models::ClipboardUpdateEvent;
+#[cfg(unix)]
+use std::os::unix::net::UnixListener;
+#[cfg(unix)]
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Mümkünse yalnızca kullanıcıya açık çalışma klasöründe, yoksa veri klasöründe bir soket yolu.
+/// Varsayılan dışında bir veri klasörüyle çalışan kopyaların soketleri ayrı adlar alır.
+fn runtime_path(stem: &str) -> PathBuf {
+    let name = match crate::database::location::instance_tag() {
+        Some(tag) => format!("{}-{}.sock", stem, tag),
+        None => format!("{}.sock", stem),
+    };
+    match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir).join(name),
+        _ => crate::database::data_dir().join(name),
+    }
+}
+
+/// Yerel istemcilerin bağlandığı soket
+pub fn socket_path() -> PathBuf {
+    runtime_path("clipcrab")
+}
+
+/// Çalışan arayüzün sonraki açılışlardan argüman aldığı soket
+pub fn instance_socket_path() -> PathBuf {
+    runtime_path("clipcrab-gui")
+}
+
+/// Soketi yalnızca kullanıcının girebildiği geçici klasörde oluşturup 0600 izniyle yerine taşı.
+/// Doğrudan `bind` edilseydi izin daraltılana kadar varsayılan izinlerle açık kalırdı.
+#[cfg(unix)]
+pub(crate) fn bind_private(path: &Path) -> Result<UnixListener, String> {
+    use std::fs;
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    // Önceki çökmeden kalan soket
+    if path.exists() {
+        fs::remove_file(path).map_err(|e| format!("Failed to remove stale socket: {}", e))?;
+    }
+
+    let staging = path.with_extension(format!("{}.d", std::process::id()));
+    fs::remove_dir_all(&staging).ok();
+    fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&staging)
+        .map_err(|e| format!("Failed to create {}: {}", staging.display(), e))?;
+    let staged = staging.join("socket");
+
+    let result = UnixListener::bind(&staged)
+        .map_err(|e| format!("Failed to bind {}: {}", path.display(), e))
+        .and_then(|listener| {
+            fs::set_permissions(&staged, fs::Permissions::from_mode(0o600))
+                .map_err(|e| format!("Failed to restrict socket permissions: {}", e))?;
+            fs::rename(&staged, path)
+                .map_err(|e| format!("Failed to move socket to {}: {}", path.display(), e))?;
+            Ok(listener)
+        });
+    fs::remove_file(&staged).ok();
+    fs::remove_dir(&staging).ok();
+    result
+}
+
+/// Soket sunucusunu başlat (yalnızca Unix); izleyiciyi çalıştıran süreç çağırır
+pub fn start(pool: Pool, app_handle: Option<tauri::AppHandle>) {
+    #[cfg(unix)]
+    if let Err(e) = server::start(pool, app_handle) {
+        eprintln!("IPC server disabled: {}", e);
+    }
+
+    #[cfg(not(unix))]
+    let _ = (pool, app_handle);
+}
+
+/// Soket sunucusunu durdur (ör. veri klasörü taşınırken); çalışan sunucu varsa `true`
+pub fn stop() -> bool {
+    #[cfg(unix)]
+    return server::stop();
+
+    #[cfg(not(unix))]
+    false
+}
+
+/// Abone istemcilere olay gönder
+pub fn broadcast(event: &ClipboardUpdateEvent) {
+    #[cfg(unix)]
+    server::broadcast(event);
+
+    #[cfg(not(unix))]
+    let _ = event;
+}
@@ -0,0 +1,196 @@
+use crate::commands;
+use crate::database::Pool;
+use crate::models::ClipboardItem;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+// JSON-RPC 2.0 hata kodları
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+pub const APP_ERROR: i64 = -32000;
+
+/// Satır başına bir JSON-RPC 2.0 isteği
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Request {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Response {
+    pub jsonrpc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<RpcError>,
+    pub id: Value,
+}
+
+impl Response {
+    pub fn ok(id: Value, result: Value) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            result: Some(result),
+            error: None,
+            id,
+        }
+    }
+
+    pub fn error(id: Value, code: i64, message: impl Into<String>) -> Self {
+        Self {
+            jsonrpc: "2.0".to_string(),
+            result: None,
+            error: Some(RpcError {
+                code,
+                message: message.into(),
+            }),
+            id,
+        }
+    }
+}
+
+/// İşlenen isteğin sonucu ve geçmişi değiştirip değiştirmediği
+pub struct Outcome {
+    pub result: Result<Value, RpcError>,
+    pub changed: Option<&'static str>,
+    pub subscribe: bool,
+}
+
+#[derive(Deserialize)]
+struct IdParams {
+    id: i64,
+}
+
+#[derive(Deserialize, Default)]
+struct ListParams {
+    limit: Option<i32>,
+    offset: Option<i32>,
+}
+
+#[derive(Deserialize, Default)]
+struct SearchParams {
+    #[serde(default)]
+    query: String,
+    limit: Option<i32>,
+    offset: Option<i32>,
+    content_filter: Option<String>,
+    tag_filter: Option<String>,
+    source_app: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct AddParams {
+    text: String,
+    source_app: Option<String>,
+}
+
+fn params<T: for<'de> Deserialize<'de> + Default>(value: Value) -> Result<T, RpcError> {
+    if value.is_null() {
+        return Ok(T::default());
+    }
+    required_params(value)
+}
+
+fn required_params<T: for<'de> Deserialize<'de>>(value: Value) -> Result<T, RpcError> {
+    serde_json::from_value(value).map_err(|e| RpcError {
+        code: INVALID_PARAMS,
+        message: e.to_string(),
+    })
+}
+
+fn app_error(message: String) -> RpcError {
+    RpcError {
+        code: APP_ERROR,
+        message,
+    }
+}
+
+fn items(items: Vec<ClipboardItem>) -> Result<Value, RpcError> {
+    serde_json::to_value(items).map_err(|e| app_error(e.to_string()))
+}
+
+/// İsteği Tauri komutlarının kullandığı fonksiyonlarla işle
+pub fn dispatch(pool: &Pool, method: &str, params_value: Value) -> Outcome {
+    let mut changed = None;
+    let mut subscribe = false;
+
+    let result = match method {
+        "list" => params::<ListParams>(params_value).and_then(|p| {
+            items(commands::get_clipboard_history_sync(
+                pool, p.limit, p.offset,
+            ))
+        }),
+        "search" => params::<SearchParams>(params_value).and_then(|p| {
+            items(commands::search_clipboard_history_sync(
+                pool,
+                p.query,
+                p.limit,
+                p.offset,
+                p.content_filter,
+                p.tag_filter,
+                p.source_app,
+            ))
+        }),
+        "get" => required_params::<IdParams>(params_value).and_then(|p| {
+            let conn = pool.get();
+            let item = commands::get_item(&conn, p.id)
+                .map_err(app_error)?
+                .ok_or_else(|| app_error(format!("Item {} not found", p.id)))?;
+            serde_json::to_value(item).map_err(|e| app_error(e.to_string()))
+        }),
+        "copy" => required_params::<IdParams>(params_value)
+            .and_then(|p| commands::copy_item(pool, p.id, false).map_err(app_error))
+            .map(|_| Value::Null),
+        "add" => required_params::<AddParams>(params_value).and_then(|p| {
+            let id = pool
+                .write(|conn| commands::add_text_item(conn, &p.text, p.source_app.as_deref()))
+                .map_err(app_error)?;
+            changed = Some("Item added over IPC");
+            Ok(json!({ "id": id }))
+        }),
+        "pin" => required_params::<IdParams>(params_value).and_then(|p| {
+            let pinned = pool
+                .write(|conn| commands::toggle_pin_item(conn, p.id))
+                .map_err(app_error)?;
+            changed = Some("Item pin changed over IPC");
+            Ok(json!({ "pinned": pinned }))
+        }),
+        "delete" => required_params::<IdParams>(params_value).and_then(|p| {
+            let undo_token = commands::new_undo_token();
+            if !pool
+                .write(|conn| commands::delete_item(conn, p.id, &undo_token))
+                .map_err(app_error)?
+            {
+                return Err(app_error(format!("Item {} not found", p.id)));
+            }
+            changed = Some("Item moved to trash over IPC");
+            Ok(json!({ "undo_token": undo_token }))
+        }),
+        "subscribe" => {
+            subscribe = true;
+            Ok(Value::Bool(true))
+        }
+        other => Err(RpcError {
+            code: METHOD_NOT_FOUND,
+            message: format!("Unknown method: {}", other),
+        }),
+    };
+
+    Outcome {
+        result,
+        changed,
+        subscribe,
+    }
+}
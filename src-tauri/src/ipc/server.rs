@@ -0,0 +1,390 @@
+use super::protocol::{self, Request, Response, APP_ERROR, INVALID_REQUEST, PARSE_ERROR};
+use crate::database::Pool;
+use crate::models::ClipboardUpdateEvent;
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use tauri::Emitter;
+
+// Bağlantı başına yazılmayı bekleyen en fazla satır; abonenin kuyruğu dolarsa okumuyor sayılır
+const QUEUE_SIZE: usize = 64;
+// Okumayan istemciye yazma bu süreden sonra başarısız olur ve bağlantı kapatılır
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+type Line = Arc<Vec<u8>>;
+
+/// Bildirim alan bağlantı; yazma kendi iş parçacığında yapılır, izleyici beklemez
+struct Subscriber {
+    connection: u64,
+    queue: SyncSender<Line>,
+    stream: UnixStream,
+}
+
+lazy_static! {
+    // "subscribe" çağıran bağlantılar
+    static ref SUBSCRIBERS: Mutex<Vec<Subscriber>> = Mutex::new(Vec::new());
+    // Çalışan sunucunun soket yolu ve durdurma işareti
+    static ref RUNNING: Mutex<Option<(PathBuf, Arc<AtomicBool>)>> = Mutex::new(None);
+}
+
+static NEXT_CONNECTION: AtomicU64 = AtomicU64::new(0);
+
+pub fn start(pool: Pool, app_handle: Option<tauri::AppHandle>) -> Result<(), String> {
+    // İzleyici kilidini tutan tek süreç sunucu çalıştırır
+    let path = super::socket_path();
+    let listener = super::bind_private(&path)?;
+    println!("IPC server listening on {}", path.display());
+    serve(listener, path, pool, app_handle);
+    Ok(())
+}
+
+/// Bağlantıları arka planda kabul et; her bağlantı kendi iş parçacığında işlenir
+fn serve(listener: UnixListener, path: PathBuf, pool: Pool, app_handle: Option<tauri::AppHandle>) {
+    let stopped = Arc::new(AtomicBool::new(false));
+    *RUNNING.lock().unwrap() = Some((path, stopped.clone()));
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stopped.load(Ordering::SeqCst) {
+                break;
+            }
+            match stream {
+                Ok(stream) => {
+                    let pool = pool.clone();
+                    let app_handle = app_handle.clone();
+                    let stopped = stopped.clone();
+                    thread::spawn(move || handle_connection(stream, stopped, pool, app_handle));
+                }
+                Err(e) => eprintln!("IPC connection failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Sunucuyu durdur: yeni bağlantı kabul edilmez, açık bağlantılar sonraki istekte kapatılır.
+/// Çalışan bir sunucu varsa `true` döner.
+pub fn stop() -> bool {
+    let Some((path, stopped)) = RUNNING.lock().unwrap().take() else {
+        return false;
+    };
+    stopped.store(true, Ordering::SeqCst);
+    // Kabul döngüsünü uyandır, ardından soketi kaldır
+    UnixStream::connect(&path).ok();
+    std::fs::remove_file(&path).ok();
+    for subscriber in SUBSCRIBERS.lock().unwrap().drain(..) {
+        subscriber.stream.shutdown(Shutdown::Both).ok();
+    }
+    true
+}
+
+fn to_line(value: &impl serde::Serialize) -> Line {
+    let mut line = serde_json::to_vec(value).unwrap_or_default();
+    line.push(b'\n');
+    Arc::new(line)
+}
+
+/// Yanıtları ve bildirimleri sırayla yazan iş parçacığı; yazılamazsa bağlantı kapatılır
+fn spawn_writer(mut stream: UnixStream) -> SyncSender<Line> {
+    let (queue, lines) = mpsc::sync_channel::<Line>(QUEUE_SIZE);
+    thread::spawn(move || {
+        for line in lines {
+            if stream
+                .write_all(&line)
+                .and_then(|_| stream.flush())
+                .is_err()
+            {
+                stream.shutdown(Shutdown::Both).ok();
+                break;
+            }
+        }
+    });
+    queue
+}
+
+fn handle_connection(
+    stream: UnixStream,
+    stopped: Arc<AtomicBool>,
+    pool: Pool,
+    app_handle: Option<tauri::AppHandle>,
+) {
+    let connection = NEXT_CONNECTION.fetch_add(1, Ordering::Relaxed);
+    let clones = stream
+        .set_write_timeout(Some(WRITE_TIMEOUT))
+        .and_then(|_| Ok((stream.try_clone()?, stream.try_clone()?)));
+    let (reader, writer_stream) = match clones {
+        Ok(clones) => clones,
+        Err(e) => {
+            eprintln!("IPC connection failed: {}", e);
+            return;
+        }
+    };
+    let reader = BufReader::new(reader);
+    // Yanıtlar aynı kuyruktan geçer; böylece abonenin bildirimleriyle sırası korunur
+    let writer = spawn_writer(writer_stream);
+
+    for line in reader.lines() {
+        let Ok(line) = line else {
+            break;
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let request: Request = match serde_json::from_str(&line) {
+            Ok(request) => request,
+            Err(e) => {
+                let response = Response::error(Value::Null, PARSE_ERROR, e.to_string());
+                if writer.send(to_line(&response)).is_err() {
+                    break;
+                }
+                continue;
+            }
+        };
+
+        let id = request.id.clone();
+        if request.jsonrpc != "2.0" {
+            let response = Response::error(
+                id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "jsonrpc must be \"2.0\"",
+            );
+            if writer.send(to_line(&response)).is_err() {
+                break;
+            }
+            continue;
+        }
+
+        if stopped.load(Ordering::SeqCst) {
+            let response = Response::error(
+                id.unwrap_or(Value::Null),
+                APP_ERROR,
+                "ClipCrab is restarting, try again",
+            );
+            writer.send(to_line(&response)).ok();
+            break;
+        }
+
+        let outcome = protocol::dispatch(&pool, &request.method, request.params);
+
+        if outcome.subscribe {
+            match stream.try_clone() {
+                Ok(stream) => SUBSCRIBERS.lock().unwrap().push(Subscriber {
+                    connection,
+                    queue: writer.clone(),
+                    stream,
+                }),
+                Err(e) => eprintln!("IPC subscribe failed: {}", e),
+            }
+        }
+        if let Some(message) = outcome.changed {
+            notify_changed(app_handle.as_ref(), message);
+        }
+
+        // Kimliksiz istekler bildirimdir; yanıt verilmez
+        let Some(id) = id else {
+            continue;
+        };
+        let response = match outcome.result {
+            Ok(result) => Response::ok(id, result),
+            Err(error) => Response::error(id, error.code, error.message),
+        };
+        if writer.send(to_line(&response)).is_err() {
+            break;
+        }
+    }
+
+    SUBSCRIBERS
+        .lock()
+        .unwrap()
+        .retain(|subscriber| subscriber.connection != connection);
+}
+
+/// Soket üzerinden yapılan değişikliği arayüze ve abonelere bildir
+fn notify_changed(app_handle: Option<&tauri::AppHandle>, message: &str) {
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: message.to_string(),
+    };
+    if let Some(app_handle) = app_handle {
+        if let Err(e) = app_handle.emit("clipboard-update", event.clone()) {
+            eprintln!("Failed to send IPC update event: {}", e);
+        }
+        crate::tray::refresh_menu(app_handle);
+    }
+    broadcast(&event);
+}
+
+/// Bildirimi abonelerin kuyruğuna bırak; izleyici iş parçacığı hiçbir zaman soket yazmasını beklemez
+pub fn broadcast(event: &ClipboardUpdateEvent) {
+    let notification = to_line(&json!({
+        "jsonrpc": "2.0",
+        "method": "clipboard-update",
+        "params": event,
+    }));
+    // Kapanmış bağlantılar listeden çıkarılır; kuyruğu dolan (okumayan) abonenin bağlantısı kapatılır
+    SUBSCRIBERS.lock().unwrap().retain(|subscriber| {
+        match subscriber.queue.try_send(notification.clone()) {
+            Ok(()) => true,
+            Err(TrySendError::Full(_)) => {
+                eprintln!("IPC subscriber is not reading, closing its connection");
+                subscriber.stream.shutdown(Shutdown::Both).ok();
+                false
+            }
+            Err(TrySendError::Disconnected(_)) => false,
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database;
+    use crate::ipc::protocol::{APP_ERROR, METHOD_NOT_FOUND};
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn send(writer: &mut UnixStream, line: &str) {
+        writeln!(writer, "{}", line).unwrap();
+    }
+
+    fn receive(reader: &mut BufReader<UnixStream>) -> Value {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn round_trip_over_socket() {
+        let _guard = database::location::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = std::env::temp_dir().join(format!("clipcrab-ipc-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        database::configure_data_dir(&["--data-dir".into(), dir.display().to_string()]).unwrap();
+
+        let path = dir.join("test.sock");
+        serve(
+            crate::ipc::bind_private(&path).unwrap(),
+            path.clone(),
+            Pool::new(),
+            None,
+        );
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        let mut writer = UnixStream::connect(&path).unwrap();
+        let mut reader = BufReader::new(writer.try_clone().unwrap());
+
+        send(&mut writer, "{not json");
+        let response = receive(&mut reader);
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+        assert!(response["id"].is_null());
+
+        send(&mut writer, r#"{"jsonrpc":"1.0","method":"list","id":1}"#);
+        assert_eq!(receive(&mut reader)["error"]["code"], INVALID_REQUEST);
+
+        send(&mut writer, r#"{"jsonrpc":"2.0","method":"nope","id":2}"#);
+        let response = receive(&mut reader);
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+        assert_eq!(response["id"], 2);
+
+        send(
+            &mut writer,
+            r#"{"jsonrpc":"2.0","method":"subscribe","id":3}"#,
+        );
+        assert_eq!(receive(&mut reader)["result"], true);
+
+        // Abone olunduğu için önce değişiklik bildirimi, ardından yanıt gelir
+        send(
+            &mut writer,
+            r#"{"jsonrpc":"2.0","method":"add","params":{"text":"hello"},"id":4}"#,
+        );
+        let notification = receive(&mut reader);
+        assert_eq!(notification["method"], "clipboard-update");
+        assert!(notification.get("id").is_none());
+        let id = receive(&mut reader)["result"]["id"].as_i64().unwrap();
+
+        send(&mut writer, r#"{"jsonrpc":"2.0","method":"list","id":5}"#);
+        let list = receive(&mut reader);
+        assert_eq!(list["result"][0]["content"], "hello");
+
+        send(
+            &mut writer,
+            &format!(
+                r#"{{"jsonrpc":"2.0","method":"get","params":{{"id":{}}},"id":6}}"#,
+                id
+            ),
+        );
+        assert_eq!(receive(&mut reader)["result"]["content"], "hello");
+
+        // Kategoriler SQL'e metin olarak eklenmez, parametre olarak bağlanır
+        send(
+            &mut writer,
+            r#"{"jsonrpc":"2.0","method":"search","params":{"content_filter":"x') OR ('1'='1"},"id":7}"#,
+        );
+        assert_eq!(receive(&mut reader)["result"], json!([]));
+
+        send(
+            &mut writer,
+            r#"{"jsonrpc":"2.0","method":"get","params":{"id":999},"id":8}"#,
+        );
+        assert_eq!(receive(&mut reader)["error"]["code"], APP_ERROR);
+
+        drop(writer);
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn subscriber_that_stops_reading_is_dropped() {
+        let _guard = database::location::TEST_LOCK
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let dir = std::env::temp_dir().join(format!("clipcrab-ipc-slow-{}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("test.sock");
+        serve(
+            crate::ipc::bind_private(&path).unwrap(),
+            path.clone(),
+            Pool::new(),
+            None,
+        );
+
+        let mut writer = UnixStream::connect(&path).unwrap();
+        let mut reader = BufReader::new(writer.try_clone().unwrap());
+        send(
+            &mut writer,
+            r#"{"jsonrpc":"2.0","method":"subscribe","id":1}"#,
+        );
+        assert_eq!(receive(&mut reader)["result"], true);
+
+        // İstemci artık okumuyor; soket tamponu ve kuyruk dolsa da yayın beklememeli
+        let event = ClipboardUpdateEvent {
+            action: "refresh".to_string(),
+            message: "x".repeat(64 * 1024),
+        };
+        let started = std::time::Instant::now();
+        for _ in 0..(QUEUE_SIZE * 4) {
+            broadcast(&event);
+        }
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(SUBSCRIBERS.lock().unwrap().is_empty());
+
+        // Sunucu bağlantıyı kapattığı için kalan veri okunduktan sonra EOF gelir
+        reader
+            .get_ref()
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        let mut rest = Vec::new();
+        std::io::Read::read_to_end(&mut reader, &mut rest).unwrap();
+        fs::remove_dir_all(&dir).ok();
+    }
+}
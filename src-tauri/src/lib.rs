@@ -1,86 +1,38 @@
+pub mod backup;
+pub mod bundle;
 pub mod clipboard;
 pub mod commands;
+pub mod daemon;
 pub mod database;
+pub mod export;
+pub mod importers;
+pub mod instance;
+pub mod ipc;
 pub mod models;
+pub mod profiles;
 pub mod security;
+pub mod settings;
+pub mod snippets;
+pub mod transform;
+pub mod tray;
 
-use tauri::{
-    image::Image,
-    menu::{MenuBuilder, MenuItem},
-    tray::TrayIconBuilder,
-    Manager, WindowEvent,
-};
-
-fn enable_tray(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
-    // Create menu items
-    let show_item = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
-    let hide_item = MenuItem::with_id(app, "hide", "Hide", true, None::<&str>)?;
-    let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
-
-    // Build menu
-    let menu = MenuBuilder::new(app)
-        .item(&show_item)
-        .separator()
-        .item(&hide_item)
-        .separator()
-        .item(&quit_item)
-        .build()?;
-
-    // Create tray icon
-    let _tray = TrayIconBuilder::with_id("tray")
-        .icon(Image::from_bytes(include_bytes!("../icons/icon.png"))?)
-        .menu(&menu)
-        .tooltip("ClipCrab - Clipboard Manager")
-        .on_menu_event(|app, event| match event.id.as_ref() {
-            "show" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.show();
-                    let _ = window.set_focus();
-                }
-            }
-            "hide" => {
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.hide();
-                }
-            }
-            "quit" => {
-                app.exit(0);
-            }
-            _ => {}
-        })
-        .on_tray_icon_event(|tray, event| {
-            match event {
-                tauri::tray::TrayIconEvent::Click {
-                    button: tauri::tray::MouseButton::Left,
-                    ..
-                } => {
-                    if let Some(app) = tray.app_handle().get_webview_window("main") {
-                        if app.is_visible().unwrap_or(false) {
-                            let _ = app.set_focus(); // Gizleme yerine sadece focus yap
-                        } else {
-                            let _ = app.show();
-                            let _ = app.set_focus();
-                        }
-                    }
-                }
-                tauri::tray::TrayIconEvent::Click {
-                    button: tauri::tray::MouseButton::Right,
-                    ..
-                } => {
-                    // Sağ tıklamada menü otomatik olarak gösterilir, hiçbir şey yapmamız gerekmez
-                }
-                _ => {}
-            }
-        })
-        .build(app)?;
-
-    Ok(())
-}
+use tauri::{Manager, WindowEvent};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Aynı veri klasöründe ikinci bir arayüz açılmaz; argümanlar çalışan örneğe iletilir
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !instance::acquire_or_forward(&args) {
+        return;
+    }
+    let launch_args = instance::LaunchArgs::parse(args);
+    // Komutlar, izleyici ve soket sunucusu aynı havuzu ve yazma sırasını kullanır
+    let pool = database::Pool::new();
+
     tauri::Builder::default()
+        .manage(pool.clone())
         .plugin(tauri_plugin_opener::init())
+        .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_autostart::init(
             tauri_plugin_autostart::MacosLauncher::LaunchAgent,
             Some(vec!["--minimized"]),
@@ -92,8 +44,11 @@ pub fn run() {
                 api.prevent_close();
             }
         })
-        .setup(|app| {
-            if let Err(e) = enable_tray(app) {
+        .setup(move |app| {
+            instance::apply_startup(app.handle(), &launch_args);
+            instance::listen(app.handle().clone());
+
+            if let Err(e) = tray::enable_tray(app) {
                 eprintln!("Tray icon setup failed: {}", e);
             }
 
@@ -104,9 +59,10 @@ pub fn run() {
             }
 
             // Veritabanı migration'ını çalıştır
-            let _ = commands::force_update_categories();
+            let _ = commands::recompute_categories(&pool);
 
-            clipboard::start_clipboard_watcher(app.handle().clone());
+            // Çalışan bir daemon varsa ikinci bir izleyici başlatılmaz
+            daemon::start_or_attach(pool, app.handle().clone());
 
             Ok(())
         })
@@ -121,7 +77,69 @@ pub fn run() {
             commands::import_clipboard_history,
             commands::is_first_run,
             commands::complete_first_run,
-            commands::force_update_categories
+            commands::force_update_categories,
+            commands::add_tag,
+            commands::remove_tag,
+            commands::list_tags,
+            commands::rename_tag,
+            commands::merge_tags,
+            commands::delete_tag,
+            commands::create_collection,
+            commands::list_collections,
+            commands::delete_collection,
+            commands::get_collection_items,
+            commands::list_snippets,
+            commands::list_snippet_folders,
+            commands::create_snippet,
+            commands::update_snippet,
+            commands::delete_snippet,
+            commands::find_snippet_by_abbreviation,
+            commands::expand_snippet,
+            commands::promote_to_snippet,
+            commands::update_clipboard_item,
+            commands::list_item_revisions,
+            commands::revert_clipboard_item,
+            commands::list_transforms,
+            commands::transform_item,
+            commands::pause_capture,
+            commands::resume_capture,
+            commands::capture_status,
+            commands::get_capture_limits,
+            commands::update_capture_limits,
+            commands::list_capture_log,
+            commands::clear_capture_log,
+            commands::get_storage_stats,
+            commands::export_bundle,
+            commands::import_bundle,
+            commands::create_backup,
+            commands::restore_backup,
+            commands::list_backups,
+            commands::run_database_maintenance,
+            commands::list_trash,
+            commands::restore_item,
+            commands::undo_delete,
+            commands::empty_trash,
+            commands::list_import_sources,
+            commands::import_from_manager,
+            commands::import_history_file,
+            commands::cancel_transfer,
+            commands::copy_clipboard_item,
+            commands::toggle_sensitive,
+            commands::list_ignored_apps,
+            commands::add_ignored_app,
+            commands::remove_ignored_app,
+            commands::list_source_apps,
+            commands::take_launch_search,
+            commands::get_settings,
+            commands::update_settings,
+            commands::reset_settings,
+            commands::get_data_directory,
+            commands::move_data_directory,
+            commands::list_profiles,
+            commands::create_profile,
+            commands::switch_profile,
+            commands::set_profile_never_capture,
+            commands::delete_profile
         ])
         .run(tauri::generate_context!())
         .expect("Failed to start Tauri application");
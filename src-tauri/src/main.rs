@@ -2,5 +2,17 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // Veri klasörü (--data-dir, CLIPCRAB_DATA_DIR, --portable) veritabanı açılmadan belirlenir
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Err(e) = clipcrab_lib::database::configure_data_dir(&args) {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    }
+
+    // Arayüzsüz çalışma: yalnızca pano izleyicisi (systemd kullanıcı birimi için)
+    if args.iter().any(|arg| arg == "--daemon") {
+        std::process::exit(clipcrab_lib::daemon::run());
+    }
+
     clipcrab_lib::run()
 }
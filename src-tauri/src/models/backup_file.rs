@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Zamanlanmış yedekleme klasöründeki bir yedek
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupFile {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub format: String,
+    pub schema_version: i32, // Veritabanının `user_version` değeri
+    pub app_version: String,
+    pub created_at: String,
+    pub profile: String,
+    pub item_count: i64,
+    pub key_wrapped: bool, // Anahtar parolayla şifrelendiyse geri yüklerken parola gerekir
+    pub checksums: BTreeMap<String, String>, // Dosya adı -> SHA-256 (hex)
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BundleManifest {
+    pub format: String,
+    pub schema_version: u32,
+    pub app_version: String,
+    pub created_at: String,
+    pub item_count: usize,
+    pub image_count: usize,
+    #[serde(default)]
+    pub skipped_count: usize, // Şifresi çözülemediği için arşive alınmayan öğeler
+    pub checksums: BTreeMap<String, String>, // Dosya adı -> SHA-256 (hex)
+}
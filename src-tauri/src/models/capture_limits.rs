@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+/// Sınırı aşan metinler için uygulanacak davranış
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LargeItemPolicy {
+    Skip,     // Hiç kaydetme, kayıt günlüğüne yaz
+    Truncate, // Sınıra kadar kırp ve işaret ekle
+    Compress, // Sıkıştırarak tamamını sakla
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct CaptureLimits {
+    pub max_text_bytes: usize,
+    pub text_policy: LargeItemPolicy,
+    pub max_image_pixels: u64, // Daha büyük resimler bu piksel sayısına küçültülür
+    pub max_image_bytes: usize, // Küçültmeden sonra PNG hâlâ büyükse atlanır
+}
+
+impl Default for CaptureLimits {
+    fn default() -> Self {
+        Self {
+            max_text_bytes: 1024 * 1024,
+            text_policy: LargeItemPolicy::Truncate,
+            max_image_pixels: 3840 * 2160,
+            max_image_bytes: 20 * 1024 * 1024,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CaptureLogEntry {
+    pub id: i64,
+    pub content_type: String,
+    pub action: String, // "skipped", "truncated" veya "downscaled"
+    pub reason: String,
+    pub size: i64, // Orijinal boyut (bayt)
+    pub source_app: Option<String>,
+    pub created_at: String,
+}
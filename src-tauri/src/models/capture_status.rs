@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CaptureStatus {
+    pub paused: bool,
+    pub remaining_seconds: Option<u64>, // Süreli duraklatmada kalan süre, süresizse None
+}
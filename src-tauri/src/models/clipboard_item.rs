@@ -9,4 +9,10 @@ pub struct ClipboardItem {
     pub image_data: Option<String>, // Base64 encoded image data
     pub created_at: String,
     pub pinned: bool,
+    #[serde(default)]
+    pub sensitive: bool, // Önizlemelerde içerik gizlenir (tepsi menüsü vb.)
+    #[serde(default)]
+    pub source_app: Option<String>, // Kopyalanan uygulama (X11'de WM_CLASS)
+    #[serde(default)]
+    pub tags: Vec<String>, // Öğeye atanmış etiketler
 }
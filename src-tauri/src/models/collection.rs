@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Kayıtlı görünüm: bir etiket + isteğe bağlı arama/kategori filtresi
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Collection {
+    pub id: i64,
+    pub name: String,
+    pub tag: String,                    // Koleksiyona ait öğeleri belirleyen etiket
+    pub query: Option<String>,          // Kayıtlı arama metni
+    pub content_filter: Option<String>, // "url,code" gibi kategori filtresi
+    pub created_at: String,
+}
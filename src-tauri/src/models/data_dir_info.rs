@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Veri klasörünün nereden belirlendiği
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DataDirSource {
+    Flag,     // --data-dir
+    Env,      // CLIPCRAB_DATA_DIR
+    Portable, // Çalıştırılabilir dosyanın yanındaki klasör
+    Moved,    // move_data_directory ile taşınmış
+    Default,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DataDirInfo {
+    pub path: String,
+    pub source: DataDirSource,
+    pub movable: bool, // Bayrak, ortam değişkeni veya taşınabilir kip klasörü sabitler
+}
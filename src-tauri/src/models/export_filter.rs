@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Dışa aktarılacak öğeleri seçer; alanlar aramadaki filtrelerle aynıdır
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ExportFilter {
+    #[serde(default)]
+    pub query: Option<String>,
+    #[serde(default)]
+    pub content_filter: Option<String>, // "all" veya virgülle ayrılmış kategoriler
+    #[serde(default)]
+    pub tag_filter: Option<String>, // Virgülle ayrılmış etiket adları; öğe hepsini taşımalı
+    #[serde(default)]
+    pub source_app: Option<String>,
+}
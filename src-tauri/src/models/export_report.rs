@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ExportReport {
+    pub exported: usize,
+    pub skipped: usize, // Şifresi çözülemediği için dışa aktarılmayan öğeler
+}
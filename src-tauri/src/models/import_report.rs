@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RejectedItem {
+    pub index: usize, // İçe aktarılan listedeki sıra (0'dan başlar)
+    pub reason: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ImportReport {
+    pub dry_run: bool, // Önizlemede hiçbir şey kaydedilmez
+    pub imported: usize,
+    pub duplicates: usize,
+    pub rejected: Vec<RejectedItem>,
+}
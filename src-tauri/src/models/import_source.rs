@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ImportSource {
+    pub id: String,
+    pub name: String,
+    pub default_path: Option<String>,
+    pub found: bool, // Varsayılan konumda geçmiş dosyası var mı?
+}
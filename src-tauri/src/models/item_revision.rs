@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ItemRevision {
+    pub id: i64,
+    pub item_id: i64,
+    pub content: String,
+    pub category: String,
+    pub created_at: String, // Bu sürümün yerini yenisinin aldığı zaman
+}
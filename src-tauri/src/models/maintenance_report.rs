@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct MaintenanceReport {
+    pub integrity_ok: bool,
+    pub integrity_errors: Vec<String>, // `PRAGMA integrity_check` çıktısı (sorun yoksa boş)
+    pub repaired: bool,                // Dizinler yeniden oluşturularak düzeltildi
+    pub full_vacuum: bool,             // Artımlı VACUUM'a geçmek için bir kez tam VACUUM yapıldı
+    pub size_before: u64,              // Veritabanı ve WAL dosyası (bayt)
+    pub size_after: u64,
+    pub undecryptable: Vec<i64>, // Etkin anahtarla açılamayan öğeler
+    pub quarantined: usize,      // Geçmişten karantina tablosuna taşınanlar
+}
@@ -1,5 +1,47 @@
+pub mod backup_file;
+pub mod backup_manifest;
+pub mod bundle_manifest;
+pub mod capture_limits;
+pub mod capture_log_entry;
+pub mod capture_status;
 pub mod clipboard_item;
 pub mod clipboard_update_event;
+pub mod collection;
+pub mod data_dir_info;
+pub mod export_filter;
+pub mod export_report;
+pub mod import_report;
+pub mod import_source;
+pub mod item_revision;
+pub mod maintenance_report;
+pub mod profile;
+pub mod settings;
+pub mod snippet;
+pub mod storage_stats;
+pub mod tag;
+pub mod transfer_progress;
+pub mod trashed_item;
 
+pub use backup_file::BackupFile;
+pub use backup_manifest::BackupManifest;
+pub use bundle_manifest::BundleManifest;
+pub use capture_limits::{CaptureLimits, LargeItemPolicy};
+pub use capture_log_entry::CaptureLogEntry;
+pub use capture_status::CaptureStatus;
 pub use clipboard_item::ClipboardItem;
 pub use clipboard_update_event::ClipboardUpdateEvent;
+pub use collection::Collection;
+pub use data_dir_info::{DataDirInfo, DataDirSource};
+pub use export_filter::ExportFilter;
+pub use export_report::ExportReport;
+pub use import_report::{ImportReport, RejectedItem};
+pub use import_source::ImportSource;
+pub use item_revision::ItemRevision;
+pub use maintenance_report::MaintenanceReport;
+pub use profile::Profile;
+pub use settings::{Settings, Theme};
+pub use snippet::Snippet;
+pub use storage_stats::StorageStats;
+pub use tag::Tag;
+pub use transfer_progress::TransferProgress;
+pub use trashed_item::TrashedItem;
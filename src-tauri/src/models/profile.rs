@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+/// Kendi veritabanı, şifreleme anahtarı ve ayarları olan ayrı bir geçmiş
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Profile {
+    pub name: String,
+    pub never_capture: bool, // Etkinken pano hiç kaydedilmez
+    pub active: bool,
+}
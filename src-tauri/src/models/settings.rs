@@ -0,0 +1,41 @@
+use super::CaptureLimits;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Theme {
+    Light,
+    Dark,
+    Auto, // Sistem temasını izle
+}
+
+/// Hem arayüzün hem de pano izleyicisinin kullandığı ayarlar
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct Settings {
+    pub max_history: Option<u32>, // None: sınırsız; sabitlenen ve koleksiyondaki öğeler sayılmaz
+    pub auto_clear_days: Option<u32>, // None: eski öğeler hiç silinmez
+    pub trash_days: Option<u32>, // Çöp kutusundaki öğeler bu süreden sonra kalıcı silinir; None: hiç
+    pub show_notifications: bool,
+    pub theme: Theme,
+    pub language: String,
+    pub capture_limits: CaptureLimits,
+    pub backup_interval_hours: Option<u32>, // None: zamanlanmış yedekleme kapalı
+    pub backup_keep: u32,                   // Saklanan en fazla zamanlanmış yedek
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_history: None,
+            auto_clear_days: None,
+            trash_days: Some(30),
+            show_notifications: true,
+            theme: Theme::Auto,
+            language: "en".to_string(),
+            capture_limits: CaptureLimits::default(),
+            backup_interval_hours: None,
+            backup_keep: 7,
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Snippet {
+    pub id: i64,
+    pub name: String,
+    pub body: String,                 // Şablon metni (veritabanında şifreli)
+    pub abbreviation: Option<String>, // Kısaltma, örn. ";addr"
+    pub folder: Option<String>,       // "Work/SQL" gibi klasör yolu
+    pub inputs: Vec<String>,          // Gövdedeki {{input:...}} alanları
+    pub created_at: String,
+    pub updated_at: String,
+}
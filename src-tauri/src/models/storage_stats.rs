@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StorageStats {
+    pub item_count: i64,
+    pub compressed_items: i64,
+    pub stored_bytes: i64, // İçerik ve resim verisinin veritabanındaki boyutu
+    pub uncompressed_bytes: i64, // Sıkıştırma olmasaydı kaplayacağı boyut
+    pub saved_bytes: i64,
+}
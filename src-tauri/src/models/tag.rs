@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Tag {
+    pub id: i64,
+    pub name: String,
+    pub item_count: i64, // Bu etiketi taşıyan öğe sayısı
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Dosyaya/dosyadan aktarımın ilerlemesi ("transfer-progress" olayı)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TransferProgress {
+    pub operation: String, // "export" veya "import"
+    pub items: usize,      // Yazılan/okunan öğe sayısı
+    pub done: u64,         // Dışa aktarmada taranan satır, içe aktarmada okunan bayt
+    pub total: Option<u64>,
+    pub finished: bool,
+}
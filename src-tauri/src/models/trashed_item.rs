@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// Çöp kutusundaki öğe; resimlerin yalnızca açıklaması listelenir
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TrashedItem {
+    pub id: i64,
+    pub content: String,
+    pub content_type: String,
+    pub category: String,
+    pub created_at: String,
+    pub deleted_at: String,
+    pub sensitive: bool,
+}
@@ -0,0 +1,301 @@
+use crate::database::{self, Pool};
+use crate::models::{ClipboardUpdateEvent, Profile};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::SystemTime;
+use tauri::{Emitter, Manager};
+
+/// Veri klasörünün kökünü kullanan profil; profillerden önceki geçmiş burada kalır
+pub const DEFAULT_PROFILE: &str = "default";
+// Diğer profiller veri klasöründe `profiles/<ad>/` altında tutulur
+pub const PROFILES_DIR: &str = "profiles";
+const PROFILES_FILE: &str = "profiles.json";
+const MAX_NAME_LEN: usize = 32;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct ProfileEntry {
+    name: String,
+    #[serde(default)]
+    never_capture: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+struct ProfileStore {
+    active: String,
+    profiles: Vec<ProfileEntry>,
+}
+
+impl Default for ProfileStore {
+    fn default() -> Self {
+        Self {
+            active: DEFAULT_PROFILE.to_string(),
+            profiles: vec![ProfileEntry {
+                name: DEFAULT_PROFILE.to_string(),
+                never_capture: false,
+            }],
+        }
+    }
+}
+
+struct Cached {
+    store: ProfileStore,
+    modified: Option<SystemTime>,
+}
+
+lazy_static! {
+    // Profil dosyasının bu süreçteki kopyası; başka süreçlerin değişiklikleri `refresh` ile okunur
+    static ref STORE: Mutex<Option<Cached>> = Mutex::new(None);
+}
+
+fn store_path() -> PathBuf {
+    database::data_dir().join(PROFILES_FILE)
+}
+
+fn modified() -> Option<SystemTime> {
+    fs::metadata(store_path())
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+fn read_store() -> ProfileStore {
+    let mut store: ProfileStore = fs::read_to_string(store_path())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+
+    // Varsayılan profil her zaman vardır; etkin profil silinmişse ona dönülür
+    if !store.profiles.iter().any(|p| p.name == DEFAULT_PROFILE) {
+        store
+            .profiles
+            .insert(0, ProfileStore::default().profiles.remove(0));
+    }
+    if !store.profiles.iter().any(|p| p.name == store.active) {
+        store.active = DEFAULT_PROFILE.to_string();
+    }
+    store
+}
+
+fn with_store<T>(f: impl FnOnce(&ProfileStore) -> T) -> T {
+    let mut guard = STORE.lock().unwrap();
+    let cached = guard.get_or_insert_with(|| Cached {
+        modified: modified(),
+        store: read_store(),
+    });
+    f(&cached.store)
+}
+
+fn save(store: ProfileStore) -> Result<(), String> {
+    let json =
+        serde_json::to_string_pretty(&store).map_err(|e| format!("Serialize error: {}", e))?;
+    // Diğer süreçler yarım yazılmış dosya okumasın
+    let path = store_path();
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, json)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save profiles: {}", e))?;
+
+    *STORE.lock().unwrap() = Some(Cached {
+        modified: modified(),
+        store,
+    });
+    Ok(())
+}
+
+fn modify(f: impl FnOnce(&mut ProfileStore) -> Result<(), String>) -> Result<(), String> {
+    refresh();
+    let mut store = with_store(ProfileStore::clone);
+    f(&mut store)?;
+    save(store)
+}
+
+/// Başka bir süreç profil dosyasını değiştirdiyse yeniden oku; etkin profil değiştiyse `true`
+pub fn refresh() -> bool {
+    let modified = modified();
+    let mut guard = STORE.lock().unwrap();
+    match guard.as_mut() {
+        Some(cached) if cached.modified == modified => false,
+        Some(cached) => {
+            let previous = std::mem::replace(&mut cached.store, read_store());
+            cached.modified = modified;
+            previous.active != cached.store.active
+        }
+        None => {
+            *guard = Some(Cached {
+                modified,
+                store: read_store(),
+            });
+            false
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.len() > MAX_NAME_LEN {
+        return Err(format!(
+            "Profile name must be 1 to {} characters",
+            MAX_NAME_LEN
+        ));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err("Profile name may only contain letters, digits, '-' and '_'".to_string());
+    }
+    Ok(())
+}
+
+fn to_profile(entry: &ProfileEntry, active: &str) -> Profile {
+    Profile {
+        name: entry.name.clone(),
+        never_capture: entry.never_capture,
+        active: entry.name == active,
+    }
+}
+
+pub fn active_name() -> String {
+    with_store(|store| store.active.clone())
+}
+
+/// Etkin profil "hiç kaydetme" olarak işaretlenmişse izleyici panoyu kaydetmez
+pub fn never_capture() -> bool {
+    with_store(|store| {
+        store
+            .profiles
+            .iter()
+            .any(|p| p.name == store.active && p.never_capture)
+    })
+}
+
+/// Profilin veritabanı ve anahtarının bulunduğu klasör
+pub fn profile_dir(name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        database::data_dir()
+    } else {
+        database::data_dir().join(PROFILES_DIR).join(name)
+    }
+}
+
+pub fn active_dir() -> PathBuf {
+    profile_dir(&active_name())
+}
+
+/// Veri klasörüne göre tüm profil klasörleri (varsayılan profil için boş yol)
+pub fn relative_dirs() -> Vec<PathBuf> {
+    with_store(|store| {
+        store
+            .profiles
+            .iter()
+            .map(|p| match p.name.as_str() {
+                DEFAULT_PROFILE => PathBuf::new(),
+                name => PathBuf::from(PROFILES_DIR).join(name),
+            })
+            .collect()
+    })
+}
+
+pub fn list() -> Vec<Profile> {
+    refresh();
+    with_store(|store| {
+        store
+            .profiles
+            .iter()
+            .map(|entry| to_profile(entry, &store.active))
+            .collect()
+    })
+}
+
+pub fn create(name: &str, never_capture: bool) -> Result<Profile, String> {
+    validate_name(name)?;
+    modify(|store| {
+        if store
+            .profiles
+            .iter()
+            .any(|p| p.name.eq_ignore_ascii_case(name))
+        {
+            return Err(format!("Profile '{}' already exists", name));
+        }
+        store.profiles.push(ProfileEntry {
+            name: name.to_string(),
+            never_capture,
+        });
+        Ok(())
+    })?;
+
+    fs::create_dir_all(profile_dir(name))
+        .map_err(|e| format!("Failed to create profile directory: {}", e))?;
+    Ok(Profile {
+        name: name.to_string(),
+        never_capture,
+        active: false,
+    })
+}
+
+pub fn set_never_capture(name: &str, never_capture: bool) -> Result<(), String> {
+    modify(|store| {
+        let entry = store
+            .profiles
+            .iter_mut()
+            .find(|p| p.name == name)
+            .ok_or_else(|| format!("Profile '{}' not found", name))?;
+        entry.never_capture = never_capture;
+        Ok(())
+    })
+}
+
+pub fn switch(name: &str) -> Result<(), String> {
+    modify(|store| {
+        if !store.profiles.iter().any(|p| p.name == name) {
+            return Err(format!("Profile '{}' not found", name));
+        }
+        store.active = name.to_string();
+        Ok(())
+    })
+}
+
+/// Profili ve tüm geçmişini sil; varsayılan ve etkin profil silinemez
+pub fn delete(name: &str) -> Result<(), String> {
+    if name == DEFAULT_PROFILE {
+        return Err("The default profile cannot be deleted".to_string());
+    }
+    modify(|store| {
+        if store.active == name {
+            return Err("Switch to another profile before deleting this one".to_string());
+        }
+        let before = store.profiles.len();
+        store.profiles.retain(|p| p.name != name);
+        if store.profiles.len() == before {
+            return Err(format!("Profile '{}' not found", name));
+        }
+        Ok(())
+    })?;
+
+    let dir = profile_dir(name);
+    if dir.exists() {
+        fs::remove_dir_all(&dir).map_err(|e| format!("Failed to remove profile data: {}", e))?;
+    }
+    Ok(())
+}
+
+/// Profil değişikliğini arayüze bildir: liste, ayarlar ve tepsi menüsü yeni profile göre yenilenir
+pub fn notify_switched(app_handle: &tauri::AppHandle) {
+    let name = active_name();
+    if let Err(e) = app_handle.emit("profile-changed", &name) {
+        eprintln!("Failed to send profile event: {}", e);
+    }
+
+    let conn = app_handle.state::<Pool>().get();
+    crate::settings::notify_changed(app_handle, &crate::settings::load(&conn));
+
+    let event = ClipboardUpdateEvent {
+        action: "refresh".to_string(),
+        message: format!("Switched to profile '{}'", name),
+    };
+    if let Err(e) = app_handle.emit("clipboard-update", event) {
+        eprintln!("Failed to send profile event: {}", e);
+    }
+    crate::tray::refresh_menu(app_handle);
+}
@@ -0,0 +1,20 @@
+/// Bu boyutun altındaki içerik sıkıştırılmaz (kazanç şifreleme ek yükünü karşılamaz)
+pub const COMPRESSION_THRESHOLD: usize = 4 * 1024;
+const COMPRESSION_LEVEL: i32 = 3;
+
+pub fn compress(data: &[u8]) -> Result<Vec<u8>, String> {
+    // Tek seferlik sıkıştırma çerçeveye orijinal boyutu da yazar
+    zstd::bulk::compress(data, COMPRESSION_LEVEL).map_err(|e| format!("Compression failed: {}", e))
+}
+
+pub fn decompress(data: &[u8]) -> Result<Vec<u8>, String> {
+    zstd::decode_all(data).map_err(|e| format!("Decompression failed: {}", e))
+}
+
+/// Sıkıştırılmış verinin açılmış boyutu (çerçeve başlığından, açmadan)
+pub fn original_size(data: &[u8]) -> Result<usize, String> {
+    match zstd::zstd_safe::get_frame_content_size(data) {
+        Ok(Some(size)) => Ok(size as usize),
+        _ => decompress(data).map(|plain| plain.len()),
+    }
+}
@@ -1,64 +1,177 @@
+use super::compression::{self, COMPRESSION_THRESHOLD};
 use aes_gcm::aead::{rand_core::RngCore, Aead, KeyInit, OsRng};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
 use base64::{engine::general_purpose, Engine as _};
 use lazy_static::lazy_static;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 const KEY_SIZE: usize = 32;
+const KEY_FILE: &str = "key.bin";
 const NONCE_SIZE: usize = 12;
+// Sıkıştırılmış içerik öneki (':' base64 alfabesinde olmadığından karışmaz)
+const COMPRESSED_PREFIX: &str = "z:";
+// AES-GCM etiketi (nonce dışında şifreli metne eklenen bayt)
+const TAG_SIZE: usize = 16;
 
 lazy_static! {
-    static ref ENCRYPTION: Mutex<Option<Aes256Gcm>> = Mutex::new(None);
+    // Her profilin kendi anahtarı var; önbellekteki şifreleyici hangi anahtar dosyasından yüklendiyle tutulur
+    static ref ENCRYPTION: Mutex<Option<(PathBuf, Aes256Gcm)>> = Mutex::new(None);
 }
 
 fn key_file_path() -> PathBuf {
-    let mut path = dirs::data_dir().expect("No data dir");
-    path.push("clipcrab");
-    fs::create_dir_all(&path).ok();
-    path.push("key.bin");
-    path
+    crate::database::profile_dir().join(KEY_FILE)
 }
 
-fn load_or_generate_key() -> Key<Aes256Gcm> {
-    let path = key_file_path();
+fn load_or_generate_key(path: &Path) -> Key<Aes256Gcm> {
     if path.exists() {
         let bytes = fs::read(path).expect("Failed to read key file");
         *Key::<Aes256Gcm>::from_slice(&bytes)
     } else {
         let mut key_bytes = [0u8; KEY_SIZE];
         OsRng.fill_bytes(&mut key_bytes);
-        fs::write(&path, key_bytes).expect("Failed to write key file");
+        fs::write(path, key_bytes).expect("Failed to write key file");
         *Key::<Aes256Gcm>::from_slice(&key_bytes)
     }
 }
 
-fn get_cipher<'a>() -> std::sync::MutexGuard<'a, Option<Aes256Gcm>> {
+/// Etkin profilin anahtarıyla şifreleyici; profil değiştiyse anahtar yeniden yüklenir
+fn cipher() -> Aes256Gcm {
+    let path = key_file_path();
     let mut guard = ENCRYPTION.lock().unwrap();
-    if guard.is_none() {
-        let key = load_or_generate_key();
-        *guard = Some(Aes256Gcm::new(&key));
+    if guard.as_ref().is_none_or(|(loaded, _)| *loaded != path) {
+        let key = load_or_generate_key(&path);
+        *guard = Some((path, Aes256Gcm::new(&key)));
     }
-    guard
+    guard.as_ref().unwrap().1.clone()
 }
 
-pub fn encrypt(plain: &str) -> Result<String, String> {
+/// Belirli bir profil klasörünün anahtarıyla şifreleyici; etkin profilden bağımsız arka plan işleri için
+pub struct ProfileCipher(Aes256Gcm);
+
+impl ProfileCipher {
+    /// Klasördeki anahtarı yükle; anahtar yoksa oluşturulmaz
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let bytes = fs::read(dir.join(KEY_FILE))
+            .map_err(|e| format!("Failed to read encryption key: {}", e))?;
+        if bytes.len() != KEY_SIZE {
+            return Err("Invalid encryption key".to_string());
+        }
+        Ok(Self(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&bytes))))
+    }
+
+    pub fn encrypt(&self, plain: &str) -> Result<String, String> {
+        encrypt_with(&self.0, plain)
+    }
+
+    pub fn decrypt(&self, data: &str) -> Result<String, String> {
+        decrypt_with(&self.0, data)
+    }
+}
+
+/// Etkin profilin anahtarı (yoksa oluşturulur); yedeğe bu konur
+pub fn key_bytes() -> Vec<u8> {
+    load_or_generate_key(&key_file_path()).to_vec()
+}
+
+/// Etkin profilin anahtarını değiştir (geri yükleme); önbellekteki şifreleyici atılır
+pub fn replace_key(key: &[u8]) -> Result<(), String> {
+    if key.len() != KEY_SIZE {
+        return Err("Invalid encryption key".to_string());
+    }
+    let path = key_file_path();
+    let tmp = path.with_extension("bin.tmp");
+    let mut guard = ENCRYPTION.lock().unwrap();
+    fs::write(&tmp, key)
+        .and_then(|_| fs::rename(&tmp, &path))
+        .map_err(|e| format!("Failed to save encryption key: {}", e))?;
+    *guard = None;
+    Ok(())
+}
+
+/// Verilen anahtar bu şifreli değeri açabiliyor mu (yedekteki anahtar veritabanına mı ait)
+pub fn key_opens(key: &[u8], data: &str) -> bool {
+    let data = data.strip_prefix(COMPRESSED_PREFIX).unwrap_or(data);
+    let Ok(bytes) = general_purpose::STANDARD.decode(data) else {
+        return false;
+    };
+    if key.len() != KEY_SIZE || bytes.len() < NONCE_SIZE {
+        return false;
+    }
+    let (nonce_bytes, ciphertext) = bytes.split_at(NONCE_SIZE);
+    Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .is_ok()
+}
+
+fn encrypt_bytes(cipher: &Aes256Gcm, plain: &[u8]) -> Result<String, String> {
     let mut nonce_bytes = [0u8; NONCE_SIZE];
     OsRng.fill_bytes(&mut nonce_bytes);
     let nonce = Nonce::from_slice(&nonce_bytes);
-    let cipher = get_cipher();
-    let cipher = cipher.as_ref().unwrap();
-    let ciphertext = cipher
-        .encrypt(nonce, plain.as_bytes())
-        .map_err(|e| e.to_string())?;
+    let ciphertext = cipher.encrypt(nonce, plain).map_err(|e| e.to_string())?;
     // nonce + ciphertext'i birleştirip base64 ile encode et
     let mut out = nonce_bytes.to_vec();
     out.extend_from_slice(&ciphertext);
     Ok(general_purpose::STANDARD.encode(out))
 }
 
+/// Eşiği aşan içerik, küçülüyorsa şifrelemeden önce sıkıştırılır
+pub fn encrypt(plain: &str) -> Result<String, String> {
+    encrypt_with(&cipher(), plain)
+}
+
+fn encrypt_with(cipher: &Aes256Gcm, plain: &str) -> Result<String, String> {
+    if plain.len() >= COMPRESSION_THRESHOLD {
+        let compressed = compression::compress(plain.as_bytes())?;
+        if compressed.len() < plain.len() {
+            return Ok(format!(
+                "{}{}",
+                COMPRESSED_PREFIX,
+                encrypt_bytes(cipher, &compressed)?
+            ));
+        }
+    }
+    encrypt_bytes(cipher, plain.as_bytes())
+}
+
+/// Boyuttan bağımsız olarak sıkıştırıp şifrele; `decrypt` öneki görüp açar
+pub fn encrypt_compressed(plain: &str) -> Result<String, String> {
+    let compressed = compression::compress(plain.as_bytes())?;
+    Ok(format!(
+        "{}{}",
+        COMPRESSED_PREFIX,
+        encrypt_bytes(&cipher(), &compressed)?
+    ))
+}
+
 pub fn decrypt(data: &str) -> Result<String, String> {
+    decrypt_with(&cipher(), data)
+}
+
+fn decrypt_with(cipher: &Aes256Gcm, data: &str) -> Result<String, String> {
+    if let Some(compressed) = data.strip_prefix(COMPRESSED_PREFIX) {
+        let plain = compression::decompress(&decrypt_bytes(cipher, compressed)?)?;
+        return String::from_utf8(plain).map_err(|e| e.to_string());
+    }
+    String::from_utf8(decrypt_bytes(cipher, data)?).map_err(|e| e.to_string())
+}
+
+pub fn is_compressed(data: &str) -> bool {
+    data.starts_with(COMPRESSED_PREFIX)
+}
+
+/// Sıkıştırılmış kaydın sıkıştırmasız saklansaydı kaplayacağı boyut
+pub fn uncompressed_stored_size(data: &str) -> Result<usize, String> {
+    let Some(compressed) = data.strip_prefix(COMPRESSED_PREFIX) else {
+        return Ok(data.len());
+    };
+    let plain_len = compression::original_size(&decrypt_bytes(&cipher(), compressed)?)?;
+    // base64(nonce + şifreli metin + etiket)
+    Ok((NONCE_SIZE + plain_len + TAG_SIZE).div_ceil(3) * 4)
+}
+
+fn decrypt_bytes(cipher: &Aes256Gcm, data: &str) -> Result<Vec<u8>, String> {
     let bytes = general_purpose::STANDARD
         .decode(data)
         .map_err(|e| e.to_string())?;
@@ -67,10 +180,5 @@ pub fn decrypt(data: &str) -> Result<String, String> {
     }
     let (nonce_bytes, ciphertext) = bytes.split_at(NONCE_SIZE);
     let nonce = Nonce::from_slice(nonce_bytes);
-    let cipher = get_cipher();
-    let cipher = cipher.as_ref().unwrap();
-    let plain = cipher
-        .decrypt(nonce, ciphertext)
-        .map_err(|e| e.to_string())?;
-    String::from_utf8(plain).map_err(|e| e.to_string())
+    cipher.decrypt(nonce, ciphertext).map_err(|e| e.to_string())
 }
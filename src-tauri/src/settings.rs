@@ -0,0 +1,127 @@
+use crate::database;
+use crate::models::Settings;
+use rusqlite::Connection;
+use serde_json::Value;
+use tauri::Emitter;
+
+const SETTINGS_KEY: &str = "settings";
+// Ayarlar tek yapıda toplanmadan önce kayıt sınırlarının tutulduğu anahtar
+const LEGACY_CAPTURE_LIMITS_KEY: &str = "capture_limits";
+const MAX_LANGUAGE_LEN: usize = 16;
+
+/// Kayıtlı ayarları oku; hiç kaydedilmemişse varsayılanlar (eski kayıt sınırları korunur)
+pub fn load(conn: &Connection) -> Settings {
+    if let Some(settings) = database::get_setting(conn, SETTINGS_KEY) {
+        return settings;
+    }
+
+    Settings {
+        capture_limits: database::get_setting(conn, LEGACY_CAPTURE_LIMITS_KEY).unwrap_or_default(),
+        ..Settings::default()
+    }
+}
+
+/// Eski sürümden gelen veritabanında ayarlar henüz kaydedilmemişse geçmişi küçültmeyen değerlerle kaydet.
+/// Eski sürümlerde geçmiş sınırı yalnızca listelenen öğe sayısını kısıtlardı; saklama kuralları bu
+/// kayıttan sonra çalışır, arayüzde saklanan seçim ise daha sonra aktarılır.
+pub fn migrate_legacy(conn: &Connection) -> Result<(), String> {
+    if database::get_setting::<Value>(conn, SETTINGS_KEY).is_some() {
+        return Ok(());
+    }
+    let has_history: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM clipboard_history)",
+            [],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Query failed: {}", e))?;
+    if !has_history {
+        return Ok(());
+    }
+    save(
+        conn,
+        &Settings {
+            max_history: None,
+            auto_clear_days: None,
+            ..load(conn)
+        },
+    )
+}
+
+pub fn validate(settings: &Settings) -> Result<(), String> {
+    if settings.max_history == Some(0) {
+        return Err("Maximum history must be greater than zero".to_string());
+    }
+    if settings.auto_clear_days == Some(0) {
+        return Err("Auto clear period must be at least one day".to_string());
+    }
+    if settings.trash_days == Some(0) {
+        return Err("Trash period must be at least one day".to_string());
+    }
+    if settings.backup_interval_hours == Some(0) {
+        return Err("Backup interval must be at least one hour".to_string());
+    }
+    if settings.backup_keep == 0 {
+        return Err("At least one backup must be kept".to_string());
+    }
+    if settings.language.is_empty()
+        || settings.language.len() > MAX_LANGUAGE_LEN
+        || !settings
+            .language
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        return Err(format!("Invalid language code: {}", settings.language));
+    }
+
+    let limits = &settings.capture_limits;
+    if limits.max_text_bytes == 0 {
+        return Err("Maximum text size must be greater than zero".to_string());
+    }
+    if limits.max_image_pixels == 0 || limits.max_image_bytes == 0 {
+        return Err("Maximum image size must be greater than zero".to_string());
+    }
+    Ok(())
+}
+
+pub fn save(conn: &Connection, settings: &Settings) -> Result<(), String> {
+    validate(settings)?;
+    database::set_setting(conn, SETTINGS_KEY, settings)
+}
+
+/// Yalnızca verilen alanları değiştir; iç içe nesneler alan alan birleştirilir
+pub fn apply_patch(current: &Settings, patch: Value) -> Result<Settings, String> {
+    let mut value = serde_json::to_value(current).map_err(|e| format!("Serialize error: {}", e))?;
+    merge(&mut value, patch, "")?;
+    serde_json::from_value(value).map_err(|e| format!("Invalid settings: {}", e))
+}
+
+fn merge(target: &mut Value, patch: Value, path: &str) -> Result<(), String> {
+    match (target, patch) {
+        (Value::Object(target), Value::Object(patch)) => {
+            for (key, value) in patch {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                let Some(slot) = target.get_mut(&key) else {
+                    return Err(format!("Unknown setting: {}", field));
+                };
+                merge(slot, value, &field)?;
+            }
+            Ok(())
+        }
+        (target, patch) => {
+            *target = patch;
+            Ok(())
+        }
+    }
+}
+
+/// Arayüze ve aynı süreçteki dinleyicilere yeni ayarları bildir
+pub fn notify_changed(app_handle: &tauri::AppHandle, settings: &Settings) {
+    if let Err(e) = app_handle.emit("settings-changed", settings) {
+        eprintln!("Failed to send settings event: {}", e);
+    }
+}
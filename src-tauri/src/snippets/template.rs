@@ -0,0 +1,151 @@
+/// Snippet gövdesinde desteklenen yer tutucular
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placeholder {
+    Date(String),  // {{date}} veya {{date:%Y-%m-%d}} (SQLite strftime formatı)
+    Clipboard,     // {{clipboard}} - panodaki mevcut metin
+    Uuid,          // {{uuid}} - rastgele UUID v4
+    Input(String), // {{input:Name}} - genişletme sırasında kullanıcıdan alınır
+}
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+fn parse_placeholder(inner: &str) -> Option<Placeholder> {
+    let inner = inner.trim();
+    let (name, arg) = match inner.split_once(':') {
+        Some((name, arg)) => (name.trim(), Some(arg)),
+        None => (inner, None),
+    };
+
+    match (name, arg) {
+        ("date", None) => Some(Placeholder::Date(DEFAULT_DATE_FORMAT.to_string())),
+        ("date", Some(format)) => Some(Placeholder::Date(format.to_string())),
+        ("clipboard", None) => Some(Placeholder::Clipboard),
+        ("uuid", None) => Some(Placeholder::Uuid),
+        ("input", Some(label)) if !label.trim().is_empty() => {
+            Some(Placeholder::Input(label.trim().to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Gövdeyi tarar; tanınan her yer tutucu için `visit` çağrılır.
+/// Tanınmayan `{{...}}` blokları olduğu gibi bırakılır (örn. Jinja/Handlebars şablonları).
+fn scan(body: &str, mut visit: impl FnMut(Option<&str>, Option<Placeholder>)) {
+    let mut rest = body;
+    while let Some(start) = rest.find("{{") {
+        let after_open = &rest[start + 2..];
+        let Some(end) = after_open.find("}}") else {
+            break;
+        };
+
+        visit(Some(&rest[..start]), None);
+        let inner = &after_open[..end];
+        match parse_placeholder(inner) {
+            Some(placeholder) => visit(None, Some(placeholder)),
+            None => visit(Some(&rest[start..start + 2 + end + 2]), None),
+        }
+        rest = &after_open[end + 2..];
+    }
+    visit(Some(rest), None);
+}
+
+/// Gövdedeki `{{input:...}}` alanlarını ilk görülme sırasıyla (tekrarsız) döndür
+pub fn input_names(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    scan(body, |_, placeholder| {
+        if let Some(Placeholder::Input(name)) = placeholder {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    });
+    names
+}
+
+/// Şablonu işle; yer tutucuların değerleri `resolve` ile üretilir
+pub fn render<F>(body: &str, mut resolve: F) -> Result<String, String>
+where
+    F: FnMut(&Placeholder) -> Result<String, String>,
+{
+    let mut output = String::with_capacity(body.len());
+    let mut error = None;
+
+    scan(body, |text, placeholder| {
+        if error.is_some() {
+            return;
+        }
+        if let Some(text) = text {
+            output.push_str(text);
+        }
+        if let Some(placeholder) = placeholder {
+            match resolve(&placeholder) {
+                Ok(value) => output.push_str(&value),
+                Err(e) => error = Some(e),
+            }
+        }
+    });
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(output),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_with(body: &str) -> Result<String, String> {
+        render(body, |placeholder| match placeholder {
+            Placeholder::Date(format) => Ok(format!("<date {}>", format)),
+            Placeholder::Clipboard => Ok("<clip>".to_string()),
+            Placeholder::Uuid => Ok("<uuid>".to_string()),
+            Placeholder::Input(name) if name == "Name" => Ok("Ada".to_string()),
+            Placeholder::Input(name) => Err(format!("Missing input: {}", name)),
+        })
+    }
+
+    #[test]
+    fn known_placeholders() {
+        assert_eq!(
+            render_with("Hi {{input:Name}}, {{ date }} {{date:%H:%M}} {{clipboard}} {{uuid}}"),
+            Ok("Hi Ada, <date %Y-%m-%d> <date %H:%M> <clip> <uuid>".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_placeholders_are_kept() {
+        assert_eq!(
+            render_with("{{user.name}} {{#if x}}{{/if}}"),
+            Ok("{{user.name}} {{#if x}}{{/if}}".to_string())
+        );
+        assert!(input_names("{{ name }}").is_empty());
+    }
+
+    #[test]
+    fn stray_braces_are_kept() {
+        assert_eq!(
+            render_with("fn main() { {} } {{uuid"),
+            Ok("fn main() { {} } {{uuid".to_string())
+        );
+        assert_eq!(render_with("{{{uuid}}}"), Ok("{{{uuid}}}".to_string()));
+        assert_eq!(render_with("{{{{uuid}}}}"), Ok("{{{{uuid}}}}".to_string()));
+    }
+
+    #[test]
+    fn missing_arguments() {
+        // Adsız girdi ve argüman alan/almayan yer tutucuların yanlış kullanımı tanınmaz
+        assert_eq!(
+            render_with("{{input}} {{input: }} {{uuid:4}} {{clipboard:x}}"),
+            Ok("{{input}} {{input: }} {{uuid:4}} {{clipboard:x}}".to_string())
+        );
+        assert_eq!(
+            render_with("{{input:Name}} {{input:City}}"),
+            Err("Missing input: City".to_string())
+        );
+        assert_eq!(
+            input_names("{{input:B}} {{input: A }} {{input:B}}"),
+            vec!["B".to_string(), "A".to_string()]
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use base64::{engine::general_purpose, Engine as _};
+
+/// JSON'u anahtar sırasını bozmadan yeniden biçimlendir.
+/// `indent` None ise tüm boşluklar kaldırılır (minify).
+fn reformat_json(input: &str, indent: Option<&str>) -> Result<String, String> {
+    // Önce geçerliliği doğrula
+    serde_json::from_str::<serde::de::IgnoredAny>(input).map_err(|e| e.to_string())?;
+
+    let mut output = String::with_capacity(input.len());
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut chars = input.trim().chars().peekable();
+
+    let newline = |output: &mut String, depth: usize| {
+        if let Some(indent) = indent {
+            output.push('\n');
+            output.push_str(&indent.repeat(depth));
+        }
+    };
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '{' | '[' => {
+                output.push(c);
+                // Boş nesne/dizi tek satırda kalır
+                while chars.peek().is_some_and(|c| c.is_whitespace()) {
+                    chars.next();
+                }
+                if matches!(chars.peek(), Some('}') | Some(']')) {
+                    output.push(chars.next().unwrap_or_default());
+                } else {
+                    depth += 1;
+                    newline(&mut output, depth);
+                }
+            }
+            '}' | ']' => {
+                depth = depth.saturating_sub(1);
+                newline(&mut output, depth);
+                output.push(c);
+            }
+            ',' => {
+                output.push(c);
+                newline(&mut output, depth);
+            }
+            ':' => {
+                output.push(c);
+                if indent.is_some() {
+                    output.push(' ');
+                }
+            }
+            c if c.is_whitespace() => {}
+            c => output.push(c),
+        }
+    }
+
+    Ok(output)
+}
+
+pub fn json_pretty(input: &str) -> Result<String, String> {
+    reformat_json(input, Some("  "))
+}
+
+pub fn json_minify(input: &str) -> Result<String, String> {
+    reformat_json(input, None)
+}
+
+/// RFC 3986 yüzde kodlaması (ayrılmamış karakterler hariç her şey kodlanır)
+pub fn url_encode(input: &str) -> String {
+    let mut output = String::with_capacity(input.len() * 3);
+    for byte in input.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                output.push(byte as char)
+            }
+            _ => output.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    output
+}
+
+pub fn url_decode(input: &str) -> Result<String, String> {
+    let bytes = input.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = input
+                .get(i + 1..i + 3)
+                .ok_or_else(|| format!("Incomplete escape at position {}", i))?;
+            // from_str_radix baştaki '+' işaretini kabul ettiği için yalnızca onaltılık rakamlara izin ver
+            let byte = Some(hex)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                .ok_or_else(|| format!("Invalid escape '%{}' at position {}", hex, i))?;
+            output.push(byte);
+            i += 3;
+        } else {
+            output.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    String::from_utf8(output).map_err(|_| "Decoded data is not valid UTF-8".to_string())
+}
+
+pub fn base64_encode(input: &str) -> String {
+    general_purpose::STANDARD.encode(input.as_bytes())
+}
+
+pub fn base64_decode(input: &str) -> Result<String, String> {
+    let cleaned: String = input.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = general_purpose::STANDARD
+        .decode(&cleaned)
+        .or_else(|_| general_purpose::URL_SAFE.decode(&cleaned))
+        .or_else(|_| general_purpose::STANDARD_NO_PAD.decode(&cleaned))
+        .or_else(|_| general_purpose::URL_SAFE_NO_PAD.decode(&cleaned))
+        .map_err(|e| e.to_string())?;
+
+    String::from_utf8(bytes).map_err(|_| "Decoded data is not valid UTF-8 text".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_pretty_keeps_key_order_and_empty_containers() {
+        let input = r#"{"b":1,"a":[1,2,{}],"c":{"d":[]}}"#;
+        let expected = "{\n  \"b\": 1,\n  \"a\": [\n    1,\n    2,\n    {}\n  ],\n  \"c\": {\n    \"d\": []\n  }\n}";
+        assert_eq!(json_pretty(input).unwrap(), expected);
+    }
+
+    #[test]
+    fn json_minify_removes_whitespace_outside_strings() {
+        let input = "{\n  \"a b\" : [ 1 , 2 ],\n  \"c\" : \"x : y, z\"\n}";
+        assert_eq!(
+            json_minify(input).unwrap(),
+            r#"{"a b":[1,2],"c":"x : y, z"}"#
+        );
+    }
+
+    #[test]
+    fn json_keeps_escapes_and_surrogate_pairs() {
+        let input = r#"{ "q" : "say \"hi\", {ok}\\", "e" : "😀" }"#;
+        assert_eq!(
+            json_minify(input).unwrap(),
+            r#"{"q":"say \"hi\", {ok}\\","e":"😀"}"#
+        );
+        assert_eq!(
+            json_minify(&json_pretty(input).unwrap()).unwrap(),
+            json_minify(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn json_rejects_invalid_input() {
+        assert!(json_pretty("{\"a\":}").is_err());
+        assert!(json_minify("[1,2").is_err());
+        assert!(json_minify("").is_err());
+    }
+
+    #[test]
+    fn url_encode_escapes_reserved_and_multibyte() {
+        assert_eq!(url_encode("a b&c=d/~"), "a%20b%26c%3Dd%2F~");
+        assert_eq!(url_encode("ğ"), "%C4%9F");
+    }
+
+    #[test]
+    fn url_decode_round_trips() {
+        let input = "çok güzel? a+b=c&d";
+        assert_eq!(url_decode(&url_encode(input)).unwrap(), input);
+        assert_eq!(url_decode("%c4%9f").unwrap(), "ğ");
+    }
+
+    #[test]
+    fn url_decode_rejects_bad_escapes() {
+        assert!(url_decode("100%").is_err());
+        assert!(url_decode("%4").is_err());
+        assert!(url_decode("%zz").is_err());
+        assert!(url_decode("%+1").is_err());
+        assert!(url_decode("%C4").is_err());
+    }
+
+    #[test]
+    fn base64_round_trips_and_accepts_variants() {
+        assert_eq!(base64_encode("hello?>"), "aGVsbG8/Pg==");
+        assert_eq!(base64_decode("aGVsbG8/Pg==").unwrap(), "hello?>");
+        assert_eq!(base64_decode("aGVsbG8_Pg==").unwrap(), "hello?>");
+        assert_eq!(base64_decode("aGVsbG8/Pg").unwrap(), "hello?>");
+        assert_eq!(base64_decode("aGVs\nbG8/\r\nPg==").unwrap(), "hello?>");
+    }
+
+    #[test]
+    fn base64_decode_rejects_invalid_input() {
+        assert!(base64_decode("not base64!").is_err());
+        // Geçerli base64 ama UTF-8 değil
+        assert!(base64_decode("/w==").is_err());
+    }
+}
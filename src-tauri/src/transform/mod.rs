@@ -0,0 +1,95 @@
+mod encoding;
+mod text;
+mod tracking;
+
+/// Kullanılabilir dönüşümler (pipeline adımlarında bu adlar kullanılır)
+pub const TRANSFORMS: &[&str] = &[
+    "trim",
+    "trim_lines",
+    "uppercase",
+    "lowercase",
+    "title_case",
+    "snake_case",
+    "kebab_case",
+    "camel_case",
+    "json_pretty",
+    "json_minify",
+    "url_encode",
+    "url_decode",
+    "base64_encode",
+    "base64_decode",
+    "strip_tracking",
+    "sort_lines",
+    "dedupe_lines",
+    "straight_quotes",
+];
+
+/// Tek bir dönüşümü uygula
+pub fn apply(name: &str, input: &str) -> Result<String, String> {
+    match name {
+        "trim" => Ok(input.trim().to_string()),
+        "trim_lines" => Ok(text::trim_lines(input)),
+        "uppercase" => Ok(input.to_uppercase()),
+        "lowercase" => Ok(input.to_lowercase()),
+        "title_case" => Ok(text::title_case(input)),
+        "snake_case" => Ok(text::join_words(input, "_", false)),
+        "kebab_case" => Ok(text::join_words(input, "-", false)),
+        "camel_case" => Ok(text::join_words(input, "", true)),
+        "json_pretty" => encoding::json_pretty(input),
+        "json_minify" => encoding::json_minify(input),
+        "url_encode" => Ok(encoding::url_encode(input)),
+        "url_decode" => encoding::url_decode(input),
+        "base64_encode" => Ok(encoding::base64_encode(input)),
+        "base64_decode" => encoding::base64_decode(input),
+        "strip_tracking" => Ok(tracking::strip_tracking_params(input)),
+        "sort_lines" => Ok(text::sort_lines(input)),
+        "dedupe_lines" => Ok(text::dedupe_lines(input)),
+        "straight_quotes" => Ok(text::straight_quotes(input)),
+        _ => Err(format!("Unknown transform: {}", name)),
+    }
+}
+
+/// Dönüşümleri sırayla uygula; ilk hatada durur
+pub fn apply_pipeline<S: AsRef<str>>(pipeline: &[S], input: &str) -> Result<String, String> {
+    if pipeline.is_empty() {
+        return Err("Pipeline is empty".to_string());
+    }
+
+    pipeline
+        .iter()
+        .try_fold(input.to_string(), |current, step| {
+            apply(step.as_ref().trim(), &current)
+                .map_err(|e| format!("{} failed: {}", step.as_ref().trim(), e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_listed_transform_is_applicable() {
+        for name in TRANSFORMS {
+            assert!(
+                !matches!(apply(name, "{}"), Err(e) if e.starts_with("Unknown transform")),
+                "{} is listed but not handled",
+                name
+            );
+        }
+        assert!(apply("reverse", "x").is_err());
+    }
+
+    #[test]
+    fn pipeline_runs_in_order_and_names_failing_step() {
+        assert_eq!(
+            apply_pipeline(&[" trim ", "snake_case", "uppercase"], "  Hello World ").unwrap(),
+            "HELLO_WORLD"
+        );
+        assert!(
+            apply_pipeline(&["trim", "base64_decode", "uppercase"], "%%")
+                .unwrap_err()
+                .starts_with("base64_decode failed: ")
+        );
+        assert!(apply_pipeline::<&str>(&[], "x").is_err());
+    }
+}
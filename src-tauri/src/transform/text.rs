@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+/// Satır sonu stilini koruyarak satırları birleştir
+fn line_ending(input: &str) -> &'static str {
+    if input.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+pub fn trim_lines(input: &str) -> String {
+    input
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(line_ending(input))
+}
+
+pub fn sort_lines(input: &str) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    lines.sort_unstable();
+    lines.join(line_ending(input))
+}
+
+/// Tekrarlanan satırları kaldır (ilk görülme sırası korunur)
+pub fn dedupe_lines(input: &str) -> String {
+    let mut seen = HashSet::new();
+    input
+        .lines()
+        .filter(|line| seen.insert(*line))
+        .collect::<Vec<_>>()
+        .join(line_ending(input))
+}
+
+pub fn title_case(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut at_word_start = true;
+
+    for c in input.chars() {
+        if c.is_alphanumeric() {
+            if at_word_start {
+                output.extend(c.to_uppercase());
+            } else {
+                output.extend(c.to_lowercase());
+            }
+            at_word_start = false;
+        } else {
+            output.push(c);
+            at_word_start = c.is_whitespace() || c == '-' || c == '_';
+        }
+    }
+
+    output
+}
+
+/// Metni kelimelere ayır ("fooBar baz-qux" -> foo, bar, baz, qux)
+fn split_words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in input.chars() {
+        if !c.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        // camelCase sınırı
+        if c.is_uppercase() && prev_lower && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_lowercase() || c.is_numeric();
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// snake_case, kebab-case ve camelCase dönüşümleri için ortak birleştirici
+pub fn join_words(input: &str, separator: &str, camel: bool) -> String {
+    split_words(input)
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| {
+            if camel && i > 0 {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect(),
+                    None => word,
+                }
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+/// Akıllı tırnakları düz tırnaklara çevir
+pub fn straight_quotes(input: &str) -> String {
+    input
+        .chars()
+        .map(|c| match c {
+            '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{00AB}' | '\u{00BB}' => '"',
+            '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2032}' => '\'',
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_tools_keep_crlf_endings() {
+        assert_eq!(trim_lines("  a \r\n\tb\r\n"), "a\r\nb");
+        assert_eq!(sort_lines("c\nA\nb"), "A\nb\nc");
+        assert_eq!(dedupe_lines("b\r\na\r\nb\r\na"), "b\r\na");
+    }
+
+    #[test]
+    fn title_case_handles_separators_and_unicode() {
+        assert_eq!(title_case("hELLO wORLD-foo_bar"), "Hello World-Foo_Bar");
+        assert_eq!(title_case("çok öyle"), "Çok Öyle");
+        assert_eq!(title_case(""), "");
+    }
+
+    #[test]
+    fn case_conversions_split_camel_and_separators() {
+        let input = "fooBar baz-qux_HTTP2go";
+        assert_eq!(join_words(input, "_", false), "foo_bar_baz_qux_http2go");
+        assert_eq!(join_words(input, "-", false), "foo-bar-baz-qux-http2go");
+        assert_eq!(join_words(input, "", true), "fooBarBazQuxHttp2go");
+        assert_eq!(join_words("  --  ", "_", false), "");
+    }
+
+    #[test]
+    fn straight_quotes_replaces_smart_quotes() {
+        assert_eq!(
+            straight_quotes("\u{201C}hi\u{201D} it\u{2019}s \u{00AB}x\u{00BB}"),
+            "\"hi\" it's \"x\""
+        );
+    }
+}
@@ -0,0 +1,114 @@
+/// Kaldırılacak izleme parametreleri (utm_* ayrıca önek olarak eşleşir)
+const TRACKING_PARAMS: &[&str] = &[
+    "fbclid",
+    "gclid",
+    "dclid",
+    "gbraid",
+    "wbraid",
+    "msclkid",
+    "yclid",
+    "twclid",
+    "igshid",
+    "mc_cid",
+    "mc_eid",
+    "_ga",
+    "_gl",
+    "_hsenc",
+    "_hsmi",
+    "mkt_tok",
+    "ref_src",
+    "oly_anon_id",
+    "oly_enc_id",
+    "vero_id",
+    "si",
+];
+
+fn is_tracking_param(pair: &str) -> bool {
+    let key = pair.split('=').next().unwrap_or("").to_lowercase();
+    key.starts_with("utm_") || TRACKING_PARAMS.contains(&key.as_str())
+}
+
+/// Tek bir URL'den izleme parametrelerini kaldır
+fn strip_url(url: &str) -> String {
+    let (without_fragment, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+    let Some((base, query)) = without_fragment.split_once('?') else {
+        return url.to_string();
+    };
+
+    let kept: Vec<&str> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !is_tracking_param(pair))
+        .collect();
+
+    let mut output = base.to_string();
+    if !kept.is_empty() {
+        output.push('?');
+        output.push_str(&kept.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        output.push('#');
+        output.push_str(fragment);
+    }
+    output
+}
+
+/// Metindeki tüm http(s) URL'lerini temizle, geri kalan metne dokunma
+pub fn strip_tracking_params(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut token = String::new();
+
+    let flush = |token: &mut String, output: &mut String| {
+        let lower = token.to_lowercase();
+        if lower.starts_with("http://") || lower.starts_with("https://") {
+            output.push_str(&strip_url(token));
+        } else {
+            output.push_str(token);
+        }
+        token.clear();
+    };
+
+    for c in input.chars() {
+        if c.is_whitespace() {
+            flush(&mut token, &mut output);
+            output.push(c);
+        } else {
+            token.push(c);
+        }
+    }
+    flush(&mut token, &mut output);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_tracking_params_and_keeps_the_rest() {
+        assert_eq!(
+            strip_tracking_params("https://x.com/a?id=1&utm_source=t&UTM_Medium=m&fbclid=z#top"),
+            "https://x.com/a?id=1#top"
+        );
+        assert_eq!(
+            strip_tracking_params("http://x.com/?gclid=1&&si=2"),
+            "http://x.com/"
+        );
+    }
+
+    #[test]
+    fn leaves_non_urls_and_surrounding_text_alone() {
+        assert_eq!(
+            strip_tracking_params("see https://x.com/?utm_id=1 and ftp://y?utm_id=2\n"),
+            "see https://x.com/ and ftp://y?utm_id=2\n"
+        );
+        assert_eq!(
+            strip_tracking_params("https://x.com/#a?utm_id=1"),
+            "https://x.com/#a?utm_id=1"
+        );
+        assert_eq!(strip_tracking_params("utm_source=x"), "utm_source=x");
+    }
+}
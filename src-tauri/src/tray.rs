@@ -0,0 +1,288 @@
+use crate::clipboard::capture;
+use crate::commands;
+use crate::database::Pool;
+use crate::profiles;
+use crate::security;
+use rusqlite::Connection;
+use std::time::Duration;
+use tauri::{
+    image::Image,
+    menu::{CheckMenuItem, Menu, MenuBuilder, MenuItem, SubmenuBuilder},
+    tray::TrayIconBuilder,
+    Manager,
+};
+
+const TRAY_ID: &str = "tray";
+const TOOLTIP: &str = "ClipCrab - Clipboard Manager";
+const TOOLTIP_PAUSED: &str = "ClipCrab - Capture paused";
+const ITEM_PREFIX: &str = "clip:";
+const PROFILE_PREFIX: &str = "profile:";
+const RECENT_ITEMS: i64 = 10;
+const PINNED_ITEMS: i64 = 20;
+const PREVIEW_LENGTH: usize = 40;
+
+fn tray_icon(paused: bool) -> tauri::Result<Image<'static>> {
+    if paused {
+        Image::from_bytes(include_bytes!("../icons/tray-paused.png"))
+    } else {
+        Image::from_bytes(include_bytes!("../icons/icon.png"))
+    }
+}
+
+/// Menü önizlemesi için içeriği kısalt; hassas öğeler gizlenir
+fn preview(content: &str, sensitive: bool) -> String {
+    if sensitive {
+        return "•••••• (sensitive)".to_string();
+    }
+
+    let first_line = content.trim().lines().next().unwrap_or("").trim();
+    if first_line.chars().count() > PREVIEW_LENGTH {
+        let truncated: String = first_line.chars().take(PREVIEW_LENGTH).collect();
+        format!("{}…", truncated)
+    } else {
+        first_line.to_string()
+    }
+}
+
+/// Menüde gösterilecek öğeleri getir (id, önizleme)
+fn menu_entries(conn: &Connection, pinned: bool, limit: i64) -> Vec<(i64, String)> {
+    let mut stmt = match conn.prepare(
+        "SELECT id, content, is_encrypted, sensitive FROM clipboard_history
+         WHERE pinned = ?1 AND deleted_at IS NULL ORDER BY id DESC LIMIT ?2",
+    ) {
+        Ok(stmt) => stmt,
+        Err(_) => return Vec::new(),
+    };
+
+    let rows: Vec<(i64, String, bool, bool)> = match stmt
+        .query_map(rusqlite::params![pinned, limit], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        }) {
+        Ok(mapped) => mapped.filter_map(Result::ok).collect(),
+        Err(_) => return Vec::new(),
+    };
+
+    rows.into_iter()
+        .map(|(id, content, is_encrypted, sensitive)| {
+            let content = if is_encrypted {
+                security::decrypt(&content).unwrap_or_default()
+            } else {
+                content
+            };
+            (id, preview(&content, sensitive))
+        })
+        .collect()
+}
+
+fn build_menu(app: &tauri::AppHandle) -> tauri::Result<Menu> {
+    let conn = app.state::<Pool>().get();
+    let paused = capture::is_paused();
+
+    let mut menu = MenuBuilder::new(app);
+
+    // Son öğeler: tıklanınca pencere açılmadan panoya kopyalanır
+    let recent = menu_entries(&conn, false, RECENT_ITEMS);
+    if recent.is_empty() {
+        menu = menu.item(&MenuItem::with_id(
+            app,
+            "no_recent",
+            "No recent items",
+            false,
+            None::<&str>,
+        )?);
+    }
+    for (id, label) in recent {
+        menu = menu.text(format!("{}{}", ITEM_PREFIX, id), label);
+    }
+
+    let pinned = menu_entries(&conn, true, PINNED_ITEMS);
+    let mut pinned_menu = SubmenuBuilder::new(app, "Pinned").enabled(!pinned.is_empty());
+    for (id, label) in pinned {
+        pinned_menu = pinned_menu.text(format!("{}{}", ITEM_PREFIX, id), label);
+    }
+    let pinned_menu = pinned_menu.build()?;
+
+    // Create menu items
+    let show_item = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+    let hide_item = MenuItem::with_id(app, "hide", "Hide", true, None::<&str>)?;
+    let quit_item = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+
+    // Kaydı duraklatma alt menüsü
+    let pause_title = if paused {
+        "Capture paused"
+    } else {
+        "Pause capture"
+    };
+    let resume_item = MenuItem::with_id(app, "resume", "Resume", paused, None::<&str>)?;
+    let pause_menu = SubmenuBuilder::new(app, pause_title)
+        .text("pause_5m", "For 5 minutes")
+        .text("pause_1h", "For 1 hour")
+        .text("pause_indefinite", "Until resumed")
+        .separator()
+        .item(&resume_item)
+        .build()?;
+
+    // Profil seçimi; seçili profil işaretli, hiç kaydetmeyenler belirtilir
+    let active_profile = profiles::active_name();
+    let mut profile_menu = SubmenuBuilder::new(app, format!("Profile: {}", active_profile));
+    for profile in profiles::list() {
+        let label = if profile.never_capture {
+            format!("{} (not recorded)", profile.name)
+        } else {
+            profile.name.clone()
+        };
+        profile_menu = profile_menu.item(&CheckMenuItem::with_id(
+            app,
+            format!("{}{}", PROFILE_PREFIX, profile.name),
+            label,
+            true,
+            profile.active,
+            None::<&str>,
+        )?);
+    }
+    let profile_menu = profile_menu.build()?;
+
+    // Build menu
+    menu.separator()
+        .item(&pinned_menu)
+        .separator()
+        .item(&show_item)
+        .separator()
+        .item(&hide_item)
+        .separator()
+        .item(&pause_menu)
+        .item(&profile_menu)
+        .separator()
+        .item(&quit_item)
+        .build()
+}
+
+/// Tepsi menüsünü güncel geçmişle yeniden oluştur
+pub fn refresh_menu(app_handle: &tauri::AppHandle) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    match build_menu(app_handle) {
+        Ok(menu) => {
+            if let Err(e) = tray.set_menu(Some(menu)) {
+                eprintln!("Failed to update tray menu: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Failed to build tray menu: {}", e),
+    }
+}
+
+pub fn enable_tray(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
+    let menu = build_menu(app.handle())?;
+
+    // Create tray icon
+    let _tray = TrayIconBuilder::with_id(TRAY_ID)
+        .icon(tray_icon(false)?)
+        .menu(&menu)
+        .tooltip(TOOLTIP)
+        .on_menu_event(|app, event| match event.id.as_ref() {
+            "show" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.show();
+                    let _ = window.set_focus();
+                }
+            }
+            "hide" => {
+                if let Some(window) = app.get_webview_window("main") {
+                    let _ = window.hide();
+                }
+            }
+            "pause_5m" => {
+                set_pause(app, capture::pause(Some(Duration::from_secs(5 * 60))));
+            }
+            "pause_1h" => {
+                set_pause(app, capture::pause(Some(Duration::from_secs(60 * 60))));
+            }
+            "pause_indefinite" => {
+                set_pause(app, capture::pause(None));
+            }
+            "resume" => {
+                set_pause(app, capture::resume());
+            }
+            "quit" => {
+                app.exit(0);
+            }
+            id if id.starts_with(PROFILE_PREFIX) => {
+                let name = &id[PROFILE_PREFIX.len()..];
+                match profiles::switch(name) {
+                    Ok(()) => profiles::notify_switched(app),
+                    Err(e) => {
+                        eprintln!("Failed to switch profile from tray: {}", e);
+                        refresh_menu(app);
+                    }
+                }
+            }
+            id => {
+                if let Some(item_id) = id
+                    .strip_prefix(ITEM_PREFIX)
+                    .and_then(|id| id.parse::<i64>().ok())
+                {
+                    if let Err(e) = commands::copy_clipboard_item(app.state::<Pool>(), item_id) {
+                        eprintln!("Failed to copy item from tray: {}", e);
+                    }
+                }
+            }
+        })
+        .on_tray_icon_event(|tray, event| {
+            match event {
+                tauri::tray::TrayIconEvent::Click {
+                    button: tauri::tray::MouseButton::Left,
+                    ..
+                } => {
+                    if let Some(app) = tray.app_handle().get_webview_window("main") {
+                        if app.is_visible().unwrap_or(false) {
+                            let _ = app.set_focus(); // Gizleme yerine sadece focus yap
+                        } else {
+                            let _ = app.show();
+                            let _ = app.set_focus();
+                        }
+                    }
+                }
+                tauri::tray::TrayIconEvent::Click {
+                    button: tauri::tray::MouseButton::Right,
+                    ..
+                } => {
+                    // Sağ tıklamada menü otomatik olarak gösterilir, hiçbir şey yapmamız gerekmez
+                }
+                _ => {}
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+/// Tepsiden duraklat/devam et; hata yalnızca günlüğe yazılır
+fn set_pause(app: &tauri::AppHandle, result: Result<(), String>) {
+    if let Err(e) = result {
+        eprintln!("Failed to change capture state from tray: {}", e);
+    }
+    capture::notify_status_changed(app);
+}
+
+/// Duraklatma durumuna göre tepsi simgesini ve ipucunu güncelle
+pub fn update_capture_status(app_handle: &tauri::AppHandle, paused: bool) {
+    let Some(tray) = app_handle.tray_by_id(TRAY_ID) else {
+        return;
+    };
+
+    match tray_icon(paused) {
+        Ok(icon) => {
+            if let Err(e) = tray.set_icon(Some(icon)) {
+                eprintln!("Failed to update tray icon: {}", e);
+            }
+        }
+        Err(e) => eprintln!("Failed to load tray icon: {}", e),
+    }
+
+    let tooltip = if paused { TOOLTIP_PAUSED } else { TOOLTIP };
+    let _ = tray.set_tooltip(Some(tooltip));
+
+    refresh_menu(app_handle);
+}
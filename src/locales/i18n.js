@@ -114,12 +114,19 @@ async function t(key, params = {}, lang = currentLanguage) {
   return result || key;
 }
 
-async function setLanguage(lang) {
+// persist: seçimi arka uçtaki ayarlara da kaydet (ayar olayından gelen değişikliklerde false)
+async function setLanguage(lang, persist = true) {
   if (!SUPPORTED_LANGUAGES[lang]) lang = DEFAULT_LANGUAGE;
   currentLanguage = lang;
   localStorage.setItem('language', lang);
   updateCurrentLanguageUI();
   
+  if (persist && window.__TAURI__ && window.__TAURI__.core) {
+    window.__TAURI__.core.invoke('update_settings', { patch: { language: lang } }).catch(error => {
+      console.error('Failed to save language:', error);
+    });
+  }
+  
   // Sayfayı yenilemek yerine dinamik güncelleme yap
   if (window.updatePageTexts) {
     await window.updatePageTexts();
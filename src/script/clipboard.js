@@ -1,4 +1,4 @@
-import { showToast, waitForI18n } from './utils.js';
+import { showToast, showUndoToast, waitForI18n } from './utils.js';
 
 const { invoke } = window.__TAURI__.core;
 
@@ -86,29 +86,8 @@ export async function loadMoreItems() {
   isLoading = true;
   
   try {
-    // Ayarlardan max limit değerini al
-    const settings = getStoredSettings();
-    const maxHistory = settings.maxHistory;
-    let maxLimit = 500;
-    
-    if (maxHistory === 'unlimited') {
-      maxLimit = 100000;
-    } else if (maxHistory) {
-      maxLimit = parseInt(maxHistory, 10);
-    }
-    
-    // Mevcut offset + PAGE_SIZE, maxLimit'ı geçmesin
-    const remainingToMax = maxLimit - currentOffset;
-    const actualPageSize = Math.min(PAGE_SIZE, remainingToMax);
-    
-    if (actualPageSize <= 0) {
-      hasMore = false;
-      isLoading = false;
-      return;
-    }
-    
     const newItems = await invoke("get_clipboard_history", { 
-      limit: actualPageSize, 
+      limit: PAGE_SIZE, 
       offset: currentOffset 
     });
     
@@ -118,8 +97,8 @@ export async function loadMoreItems() {
       clipboardHistory = [...clipboardHistory, ...newItems];
       currentOffset += newItems.length;
       
-      // Max limite ulaştık mı kontrol et
-      if (currentOffset >= maxLimit || newItems.length < actualPageSize) {
+      // Geçmiş sınırı arka uçta uygulanır; son sayfaya ulaştık mı kontrol et
+      if (newItems.length < PAGE_SIZE) {
         hasMore = false;
       }
     }
@@ -146,39 +125,12 @@ export function getTotalCount() {
   return totalCount;
 }
 
-// Ayarları al (ui.js'den bağımsız olarak burada da tanımla)
-function getStoredSettings() {
-  try {
-    const stored = localStorage.getItem('clipcrab_settings');
-    const defaultSettings = {
-      maxHistory: '500',
-      autoClear: 'never',
-      showNotifications: true,
-      soundEnabled: false,
-      theme: 'auto',
-      compactMode: false
-    };
-    
-    return stored ? { ...defaultSettings, ...JSON.parse(stored) } : defaultSettings;
-  } catch (error) {
-    console.error('Error loading stored settings:', error);
-    return {
-      maxHistory: '500',
-      autoClear: 'never',
-      showNotifications: true,
-      soundEnabled: false,
-      theme: 'auto',
-      compactMode: false
-    };
-  }
-}
-
 export async function clearAllHistory() {
   await waitForI18n();
   // Tüm metinleri await ile çek
   const title = window.i18n ? await window.i18n.t('header.clear_all') : 'Clear All History';
   const message = window.i18n ? await window.i18n.t('modal.delete_message') : 'Are you sure you want to delete all clipboard history?';
-  const warning = window.i18n ? await window.i18n.t('modal.clear_warning') : 'This action cannot be undone!';
+  const warning = window.i18n ? await window.i18n.t('modal.clear_warning') : 'Items are moved to the trash and can be restored.';
   const cancel = window.i18n ? await window.i18n.t('modal.cancel') : 'Cancel';
   const confirm = window.i18n ? await window.i18n.t('clipboard.delete') : 'Delete All';
 
@@ -209,10 +161,11 @@ export async function clearAllHistory() {
   
   modal.querySelector('#confirm-clear').addEventListener('click', async () => {
     try {
-      await invoke("clear_all_history");
+      const undoToken = await invoke("clear_all_history");
       document.body.removeChild(modal);
       // UI'yi hemen güncelle
       await loadClipboardHistory();
+      await offerUndo(undoToken, 'trash.cleared');
     } catch (error) {
       console.error('Error clearing history:', error);
       showToast('Failed to clear history!', 'error');
@@ -229,9 +182,10 @@ export async function clearAllHistory() {
 
 export async function deleteHistoryItem(id) {
   try {
-    await invoke("delete_clipboard_item", { id });
+    const undoToken = await invoke("delete_clipboard_item", { id });
     // UI'yi hemen güncelle
     await loadClipboardHistory();
+    await offerUndo(undoToken, 'trash.moved');
   } catch (error) {
     console.error('Delete error:', error);
     showToast('Delete operation failed!', 'error');
@@ -239,6 +193,20 @@ export async function deleteHistoryItem(id) {
   }
 }
 
+// Silme/temizleme sonrası geri al bildirimi göster
+async function offerUndo(undoToken, messageKey) {
+  await waitForI18n();
+  showUndoToast(await window.i18n.t(messageKey), await window.i18n.t('trash.undo'), async () => {
+    try {
+      await invoke("undo_delete", { undoToken });
+      await loadClipboardHistory();
+    } catch (error) {
+      console.error('Undo error:', error);
+      showToast(await window.i18n.t('trash.undo_failed'), 'error');
+    }
+  });
+}
+
 export async function togglePin(id) {
   try {
     await invoke("toggle_pin", { id });
@@ -1,6 +1,7 @@
-import { elements, setupInfiniteScroll } from './ui.js';
+import { elements, setupInfiniteScroll, loadTrash } from './ui.js';
 import { loadClipboardHistory, clearAllHistory, setSearchQuery, filterHistory, toggleContentFilter, getActiveFilters } from './clipboard.js';
 import { showToast } from './utils.js';
+import { getAppSettings } from './settings.js';
 
 // Event Handlers
 export function handleClipboardUpdate(eventData) {
@@ -10,8 +11,24 @@ export function handleClipboardUpdate(eventData) {
     console.log('Refreshing list...');
     // Listeyi yenile
     loadClipboardHistory();
-    showToast(eventData.message, 'success');
+    if (document.getElementById('trash-page')?.classList.contains('active')) {
+      loadTrash();
+    }
+    if (getAppSettings().show_notifications) {
+      showToast(eventData.message, 'success');
+    }
+  }
+}
+
+// Komut satırından (--search) istenen aramayı uygula
+export function applySearchRequest(query) {
+  if (typeof query !== 'string') return;
+  elements.searchInput.value = query;
+  setSearchQuery(query);
+  if (elements.clearSearchBtn) {
+    elements.clearSearchBtn.style.display = query.length > 0 ? 'flex' : 'none';
   }
+  elements.searchInput.focus();
 }
 
 // Event Listeners
@@ -25,6 +42,13 @@ export function setupEventListeners() {
     console.error('Event listener error:', error);
   });
   
+  // Zaten çalışırken yeniden açıldığında gelen arama
+  window.__TAURI__.event.listen('search-requested', (event) => {
+    applySearchRequest(event.payload);
+  }).catch(error => {
+    console.error('Event listener error:', error);
+  });
+  
   // Refresh button
   elements.refreshBtn.addEventListener("click", loadClipboardHistory);
   
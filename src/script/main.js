@@ -1,7 +1,8 @@
 import { initI18n, updatePageTexts, loadSettings, applyTheme } from './ui.js';
 import { loadClipboardHistory } from './clipboard.js';
-import { setupEventListeners, setupServiceWorker } from './events.js';
+import { setupEventListeners, setupServiceWorker, applySearchRequest } from './events.js';
 import { initUpdater } from './updater.js';
+import { loadAppSettings } from './settings.js';
 
 // Load app version dynamically
 async function loadAppVersion() {
@@ -28,6 +29,9 @@ window.addEventListener("DOMContentLoaded", async () => {
   // Setup event listeners early
   setupEventListeners();
   
+  // Arka uçtaki ayarlar (tema, dil, bildirimler)
+  await loadAppSettings();
+  
   // Initialize updater
   initUpdater();
   
@@ -44,10 +48,28 @@ window.addEventListener("DOMContentLoaded", async () => {
   // Paralel işlemlerin bitmesini bekle
   await Promise.all([clipboardPromise, firstRunPromise]);
   
+  // --search ile açıldıysa aramayı uygula
+  await applyLaunchSearch();
+  
   // Setup service worker
   setupServiceWorker();
 });
 
+// Açılış argümanlarındaki arama
+async function applyLaunchSearch() {
+  try {
+    const { invoke } = window.__TAURI__.core || {};
+    if (!invoke) return;
+    
+    const query = await invoke('take_launch_search');
+    if (query) {
+      applySearchRequest(query);
+    }
+  } catch (error) {
+    console.error('Launch search failed:', error);
+  }
+}
+
 // İlk çalıştırma kontrolü
 async function checkFirstRun() {
   try {
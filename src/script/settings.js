@@ -0,0 +1,108 @@
+const { invoke } = window.__TAURI__.core || {};
+
+// Arka uç yanıt vermezse kullanılacak varsayılanlar (Rust tarafındaki Settings::default ile aynı)
+const DEFAULT_SETTINGS = {
+  max_history: null,
+  auto_clear_days: null,
+  trash_days: 30,
+  show_notifications: true,
+  theme: 'auto',
+  language: 'en',
+  backup_interval_hours: null,
+  backup_keep: 7
+};
+// Ayarlar arka uca taşınmadan önce localStorage'da tutulan değerler bir kez aktarılır
+const MIGRATED_KEY = 'settings_migrated';
+
+let appSettings = { ...DEFAULT_SETTINGS };
+const listeners = [];
+
+export function getAppSettings() {
+  return appSettings;
+}
+
+// Ayar değiştiğinde (bu pencereden ya da başka bir yerden) çağrılır
+export function onSettingsChanged(callback) {
+  listeners.push(callback);
+}
+
+export async function loadAppSettings() {
+  if (!invoke) return appSettings;
+  
+  try {
+    appSettings = await invoke('get_settings');
+    await migrateLocalSettings();
+  } catch (error) {
+    console.error('Failed to load settings:', error);
+  }
+  
+  applySettings(appSettings);
+  
+  window.__TAURI__.event.listen('settings-changed', (event) => {
+    applySettings(event.payload);
+  }).catch(error => {
+    console.error('Event listener error:', error);
+  });
+  
+  return appSettings;
+}
+
+// Yalnızca verilen alanları değiştir
+export async function updateAppSettings(patch) {
+  const settings = await invoke('update_settings', { patch });
+  applySettings(settings);
+  return settings;
+}
+
+export async function resetAppSettings() {
+  const settings = await invoke('reset_settings');
+  applySettings(settings);
+  return settings;
+}
+
+function applySettings(settings) {
+  appSettings = { ...DEFAULT_SETTINGS, ...settings };
+  
+  // İlk boyamada titremeyi önlemek için tema ve dil önbellekte tutulur
+  localStorage.setItem('theme', appSettings.theme);
+  if (window.i18n && window.i18n.getCurrentLanguage() !== appSettings.language) {
+    window.i18n.setLanguage(appSettings.language, false);
+  }
+  
+  listeners.forEach(callback => callback(appSettings));
+}
+
+async function migrateLocalSettings() {
+  if (localStorage.getItem(MIGRATED_KEY)) return;
+  
+  const patch = {};
+  const theme = localStorage.getItem('theme');
+  if (theme) patch.theme = theme;
+  const language = localStorage.getItem('language');
+  if (language) patch.language = language;
+  
+  try {
+    const stored = JSON.parse(localStorage.getItem('clipcrab_settings') || '{}');
+    if (stored.maxHistory === 'unlimited') {
+      patch.max_history = null;
+    } else if (parseInt(stored.maxHistory, 10) > 0) {
+      patch.max_history = parseInt(stored.maxHistory, 10);
+    }
+    if (stored.autoClear === 'never') {
+      patch.auto_clear_days = null;
+    } else if (parseInt(stored.autoClear, 10) > 0) {
+      patch.auto_clear_days = parseInt(stored.autoClear, 10);
+    }
+    if (typeof stored.showNotifications === 'boolean') {
+      patch.show_notifications = stored.showNotifications;
+    }
+  } catch (error) {
+    console.error('Error loading stored settings:', error);
+  }
+  
+  if (Object.keys(patch).length > 0) {
+    appSettings = await invoke('update_settings', { patch });
+  }
+  localStorage.removeItem('clipcrab_settings');
+  localStorage.setItem(MIGRATED_KEY, 'true');
+}
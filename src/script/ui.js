@@ -1,6 +1,8 @@
 import { waitForI18n, formatTimeAgo, truncateText, getTextIcon, getTextTypeLabel, showToast } from './utils.js';
 import { copyToClipboard, deleteHistoryItem, togglePin, getClipboardHistory, getFilteredHistory, getSearchQuery, loadClipboardHistory, loadMoreItems, canLoadMore, getIsLoading, getTotalCount, hasActiveFilter, canLoadMoreFiltered, loadMoreFilteredItems } from './clipboard.js';
 
+import { getAppSettings, updateAppSettings, onSettingsChanged } from './settings.js';
+
 const { invoke } = window.__TAURI__.core || {};
 
 // Autostart plugin API
@@ -23,6 +25,7 @@ export const elements = {
   toast: document.getElementById("toast"),
   // Navigation
   historyTab: document.getElementById("history-tab"),
+  trashTab: document.getElementById("trash-tab"),
   importexportTab: document.getElementById("importexport-tab"),
   settingsTab: document.getElementById("settings-tab"),
   historyPage: document.getElementById("history-page"),
@@ -50,12 +53,14 @@ export function initI18n() {
   initNavigation();
   initSettings();
   initImportExport();
+  initTrash();
   updatePageTexts();
 }
 
 // Navigation
 export function initNavigation() {
   if (elements.historyTab) elements.historyTab.addEventListener('click', () => switchPage('history'));
+  if (elements.trashTab) elements.trashTab.addEventListener('click', () => switchPage('trash'));
   if (elements.importexportTab) elements.importexportTab.addEventListener('click', () => switchPage('importexport'));
   if (elements.settingsTab) elements.settingsTab.addEventListener('click', () => switchPage('settings'));
 }
@@ -72,6 +77,8 @@ export function switchPage(pageName) {
   
   if (pageName === 'history') {
     loadClipboardHistory();
+  } else if (pageName === 'trash') {
+    loadTrash();
   } else if (pageName === 'settings') {
     loadSettings();
   }
@@ -88,11 +95,16 @@ export function initSettings() {
         themeButtons.forEach(b => b.classList.remove('active'));
         this.classList.add('active');
         applyTheme(theme);
-        localStorage.setItem('theme', theme);
+        updateAppSettings({ theme }).catch(error => {
+          console.error('Failed to save theme:', error);
+        });
       });
     });
   }
   
+  initDataDirectory();
+  initProfiles();
+  
   // Autostart toggle
   if (elements.autostartCheckbox) {
     elements.autostartCheckbox.addEventListener('change', async function() {
@@ -122,7 +134,7 @@ export function initSettings() {
 
 export async function loadSettings() {
   // Load current theme
-  const currentTheme = localStorage.getItem('theme') || 'auto';
+  const currentTheme = getAppSettings().theme;
   if (elements.themeSelector) {
     elements.themeSelector.querySelectorAll('.theme-btn').forEach(btn => {
       btn.classList.toggle('active', btn.dataset.theme === currentTheme);
@@ -152,6 +164,9 @@ export async function loadSettings() {
     }
   }
   
+  await loadDataDirectory();
+  await loadProfiles();
+  
   // Load autostart status
   if (elements.autostartCheckbox) {
     try {
@@ -172,6 +187,175 @@ export async function loadSettings() {
   }
 }
 
+// Veri klasörü ve taşıma düğmesi
+async function loadDataDirectory() {
+  const pathEl = document.getElementById('data-dir-path');
+  const fixedEl = document.getElementById('data-dir-fixed');
+  const moveBtn = document.getElementById('move-data-dir');
+  if (!pathEl || !moveBtn) return;
+  
+  try {
+    const info = await invoke('get_data_directory');
+    pathEl.textContent = info.path;
+    moveBtn.disabled = !info.movable;
+    if (fixedEl) fixedEl.style.display = info.movable ? 'none' : 'block';
+  } catch (error) {
+    console.error('Failed to load data directory:', error);
+  }
+}
+
+function initDataDirectory() {
+  const moveBtn = document.getElementById('move-data-dir');
+  if (!moveBtn) return;
+  
+  moveBtn.addEventListener('click', async () => {
+    const target = await window.__TAURI__.dialog.open({ directory: true, multiple: false });
+    if (!target) return;
+    
+    moveBtn.disabled = true;
+    try {
+      await invoke('move_data_directory', { target });
+      showToast(await window.i18n.t('settings.data.moved'), 'success');
+    } catch (error) {
+      console.error('Move data directory error:', error);
+      showToast(`${await window.i18n.t('settings.data.move_error')}: ${error}`, 'error');
+      moveBtn.disabled = false;
+    }
+  });
+}
+
+// Profiller: seçim, "hiç kaydetme" ve ekleme/silme
+async function loadProfiles() {
+  const select = document.getElementById('profile-select');
+  const neverCapture = document.getElementById('profile-never-capture');
+  const deleteBtn = document.getElementById('delete-profile');
+  if (!select) return;
+  
+  try {
+    const profiles = await invoke('list_profiles');
+    select.innerHTML = '';
+    profiles.forEach(profile => {
+      const option = document.createElement('option');
+      option.value = profile.name;
+      option.textContent = profile.name;
+      option.selected = profile.active;
+      select.appendChild(option);
+    });
+    
+    const active = profiles.find(profile => profile.active);
+    if (neverCapture && active) neverCapture.checked = active.never_capture;
+    if (deleteBtn) deleteBtn.disabled = !active || active.name === 'default';
+  } catch (error) {
+    console.error('Failed to load profiles:', error);
+  }
+}
+
+async function showProfileError(error) {
+  console.error('Profile error:', error);
+  showToast(`${await window.i18n.t('settings.profiles.error')}: ${error}`, 'error');
+}
+
+// Profil silme onayı; silinen profilin tüm geçmişi gider
+async function confirmDeleteProfile(name) {
+  const title = await window.i18n.t('settings.profiles.title');
+  const message = await window.i18n.t('settings.profiles.delete_confirm', { name });
+  const warning = await window.i18n.t('modal.clear_warning');
+  const cancel = await window.i18n.t('modal.cancel');
+  const confirmText = await window.i18n.t('clipboard.delete');
+  
+  const modal = document.createElement('div');
+  modal.className = 'modal-overlay';
+  modal.innerHTML = `
+    <div class="modal">
+      <div class="modal-header">
+        <h3><i class="fas fa-exclamation-triangle"></i> ${title}</h3>
+      </div>
+      <div class="modal-body">
+        <p>${message}</p>
+        <p><strong>${warning}</strong></p>
+      </div>
+      <div class="modal-footer">
+        <button class="btn btn-secondary" id="cancel-delete-profile">${cancel}</button>
+        <button class="btn btn-danger" id="confirm-delete-profile">${confirmText}</button>
+      </div>
+    </div>
+  `;
+  document.body.appendChild(modal);
+  
+  const close = () => modal.remove();
+  modal.querySelector('#cancel-delete-profile').addEventListener('click', close);
+  modal.addEventListener('click', (e) => {
+    if (e.target === modal) close();
+  });
+  modal.querySelector('#confirm-delete-profile').addEventListener('click', async () => {
+    close();
+    try {
+      // Etkin profil silinemez; önce varsayılana geç
+      await invoke('switch_profile', { name: 'default' });
+      await invoke('delete_profile', { name });
+    } catch (error) {
+      await showProfileError(error);
+    }
+    await loadProfiles();
+  });
+}
+
+function initProfiles() {
+  const select = document.getElementById('profile-select');
+  const neverCapture = document.getElementById('profile-never-capture');
+  const nameInput = document.getElementById('new-profile-name');
+  const createBtn = document.getElementById('create-profile');
+  const deleteBtn = document.getElementById('delete-profile');
+  if (!select) return;
+  
+  select.addEventListener('change', async () => {
+    try {
+      await invoke('switch_profile', { name: select.value });
+    } catch (error) {
+      await showProfileError(error);
+      await loadProfiles();
+    }
+  });
+  
+  if (neverCapture) {
+    neverCapture.addEventListener('change', async () => {
+      try {
+        await invoke('set_profile_never_capture', { name: select.value, neverCapture: neverCapture.checked });
+      } catch (error) {
+        neverCapture.checked = !neverCapture.checked;
+        await showProfileError(error);
+      }
+    });
+  }
+  
+  if (createBtn && nameInput) {
+    createBtn.addEventListener('click', async () => {
+      const name = nameInput.value.trim();
+      if (!name) return;
+      try {
+        await invoke('create_profile', { name });
+        await invoke('switch_profile', { name });
+        nameInput.value = '';
+      } catch (error) {
+        await showProfileError(error);
+      }
+      await loadProfiles();
+    });
+  }
+  
+  if (deleteBtn) {
+    deleteBtn.addEventListener('click', () => confirmDeleteProfile(select.value));
+  }
+  
+  // Tepsiden veya başka bir süreçten yapılan profil değişikliği
+  window.__TAURI__.event.listen('profile-changed', async (event) => {
+    await loadProfiles();
+    showToast(await window.i18n.t('settings.profiles.switched', { name: event.payload }), 'success');
+  }).catch(error => {
+    console.error('Event listener error:', error);
+  });
+}
+
 export function applyTheme(theme) {
   const root = document.documentElement;
   root.classList.remove('theme-light', 'theme-dark');
@@ -188,10 +372,20 @@ export function applyTheme(theme) {
   }
 }
 
-// Initialize theme on load
+// Initialize theme on load (arka uç ayarları yüklenene kadar önbellekteki tema)
 const savedTheme = localStorage.getItem('theme') || 'auto';
 applyTheme(savedTheme);
 
+// Başka bir yerden değişen tema hemen uygulanır
+onSettingsChanged(settings => {
+  applyTheme(settings.theme);
+  if (elements.themeSelector) {
+    elements.themeSelector.querySelectorAll('.theme-btn').forEach(btn => {
+      btn.classList.toggle('active', btn.dataset.theme === settings.theme);
+    });
+  }
+});
+
 // Update texts
 export async function updatePageTexts() {
   await waitForI18n();
@@ -222,10 +416,12 @@ export async function updatePageTexts() {
   
   // Navigation
   const historyTab = document.querySelector('#history-tab span');
+  const trashTab = document.querySelector('#trash-tab span');
   const importexportTab = document.querySelector('#importexport-tab span');
   const settingsTab = document.querySelector('#settings-tab span');
   
   if (historyTab) historyTab.textContent = await window.i18n.t('navigation.history');
+  if (trashTab) trashTab.textContent = await window.i18n.t('navigation.trash');
   if (importexportTab) importexportTab.textContent = await window.i18n.t('navigation.importexport');
   if (settingsTab) settingsTab.textContent = await window.i18n.t('navigation.settings');
   
@@ -549,57 +745,179 @@ export function updateStats() {
 }
 
 // Import/Export
+// Dosya uzantısından dışa aktarma biçimi
+const EXPORT_FORMATS = [
+  ['.tar.zst', 'archive'],
+  ['.ndjson', 'ndjson'],
+  ['.jsonl', 'ndjson'],
+  ['.json', 'json'],
+  ['.csv', 'csv'],
+  ['.md', 'markdown'],
+  ['.html', 'html'],
+];
+
+function exportFormatFor(path) {
+  const lower = path.toLowerCase();
+  const match = EXPORT_FORMATS.find(([ext]) => lower.endsWith(ext));
+  return match ? match[1] : 'archive';
+}
+
+// Süren aktarım; düğmeye tekrar basmak onu iptal eder
+let activeTransfer = null;
+
+async function runTransfer(button, task) {
+  if (activeTransfer) {
+    await invoke('cancel_transfer');
+    return;
+  }
+
+  const label = button.querySelector('span');
+  const originalText = label.textContent;
+  const unlisten = await window.__TAURI__.event.listen('transfer-progress', (event) => {
+    const { done, total, items } = event.payload;
+    const percent = total ? Math.floor((done / total) * 100) : null;
+    label.textContent = percent !== null ? `${percent}% (${items}) ✕` : `${items} ✕`;
+  });
+
+  activeTransfer = button;
+  try {
+    await task();
+  } finally {
+    activeTransfer = null;
+    unlisten();
+    label.textContent = originalText;
+  }
+}
+
+// Trash
+export function initTrash() {
+  const emptyBtn = document.getElementById('empty-trash');
+  if (!emptyBtn) return;
+
+  emptyBtn.addEventListener('click', async () => {
+    await waitForI18n();
+    if (!confirm(await window.i18n.t('trash.empty_confirm'))) return;
+    try {
+      await invoke('empty_trash');
+      await loadTrash();
+    } catch (error) {
+      console.error('Empty trash error:', error);
+      showToast(await window.i18n.t('trash.empty_failed'), 'error');
+    }
+  });
+}
+
+export async function loadTrash() {
+  const list = document.getElementById('trash-list');
+  const emptyState = document.getElementById('trash-empty-state');
+  if (!list) return;
+
+  try {
+    const items = await invoke('list_trash');
+    list.innerHTML = '';
+    if (emptyState) emptyState.style.display = items.length === 0 ? 'flex' : 'none';
+    for (const [index, item] of items.entries()) {
+      list.appendChild(await createTrashItem(item, index));
+    }
+  } catch (error) {
+    console.error('Load trash error:', error);
+    showToast(await window.i18n.t('trash.load_failed'), 'error');
+  }
+}
+
+async function createTrashItem(item, index) {
+  await waitForI18n();
+  const div = document.createElement('div');
+  div.className = 'history-item trash-item fade-in';
+  div.style.animationDelay = `${index * 0.05}s`;
+
+  const deletedAgo = await formatTimeAgo(item.deleted_at);
+  const typeLabel = await getTextTypeLabel(item.content, item.content_type);
+  const textIcon = getTextIcon(item.content, item.content_type);
+  const restoreText = await window.i18n.t('trash.restore');
+  const content = item.sensitive ? '••••••••' : item.content;
+
+  div.innerHTML = `
+    <div class="content line-clamp-3">${escapeHtml(content)}</div>
+    <div class="meta">
+      <span class="meta-item"><i class="fas fa-trash"></i>${deletedAgo}</span>
+      <span class="meta-item"><i class="${textIcon}"></i>${typeLabel}</span>
+      <div class="actions">
+        <button class="action-btn restore" title="${restoreText}">
+          <i class="fas fa-rotate-left"></i>
+        </button>
+      </div>
+    </div>
+  `;
+
+  div.querySelector('.action-btn.restore').addEventListener('click', async () => {
+    try {
+      await invoke('restore_item', { id: item.id });
+      await loadTrash();
+    } catch (error) {
+      console.error('Restore error:', error);
+      showToast(await window.i18n.t('trash.restore_failed'), 'error');
+    }
+  });
+
+  return div;
+}
+
 export function initImportExport() {
   const exportBtn = document.getElementById('export-json');
   const importBtn = document.getElementById('import-json');
-  const importFile = document.getElementById('import-file');
+  const dialog = window.__TAURI__.dialog;
   
   if (exportBtn) {
-    exportBtn.addEventListener('click', async () => {
+    exportBtn.addEventListener('click', () => runTransfer(exportBtn, async () => {
+      const path = await dialog.save({
+        defaultPath: 'clipboard_history.tar.zst',
+        filters: [
+          { name: 'ClipCrab archive', extensions: ['tar.zst'] },
+          { name: 'NDJSON', extensions: ['ndjson', 'jsonl'] },
+          { name: 'JSON', extensions: ['json'] },
+          { name: 'CSV', extensions: ['csv'] },
+          { name: 'Markdown', extensions: ['md'] },
+          { name: 'HTML', extensions: ['html'] },
+        ],
+      });
+      if (!path) return;
+
       try {
-        const json = await invoke('export_clipboard_history');
-        const blob = new Blob([json], { type: 'application/json' });
-        const url = URL.createObjectURL(blob);
-        const a = document.createElement('a');
-        a.href = url;
-        a.download = 'clipboard_history.json';
-        document.body.appendChild(a);
-        a.click();
-        setTimeout(() => {
-          document.body.removeChild(a);
-          URL.revokeObjectURL(url);
-        }, 100);
-        showToast('Export successful!', 'success');
+        const message = await invoke('export_clipboard_history', { format: exportFormatFor(path), path });
+        // Mesaj, çözülemediği için atlanan öğeleri de bildirir
+        showToast(message, 'success');
       } catch (e) {
-        showToast('Export failed', 'error');
+        showToast(e === 'Transfer cancelled' ? 'Export cancelled' : 'Export failed', 'error');
         console.error('Export error:', e);
       }
-    });
+    }));
   }
   
-  if (importBtn && importFile) {
-    importBtn.addEventListener('click', () => {
-      importFile.value = '';
-      importFile.click();
-    });
-    
-    importFile.addEventListener('change', async (e) => {
-      const file = e.target.files[0];
-      if (!file) return;
-      
-      const reader = new FileReader();
-      reader.onload = async (ev) => {
-        try {
-          const json = ev.target.result;
-          const count = await invoke('import_clipboard_history', { jsonData: json });
-          showToast(`Imported ${count} items!`, 'success');
-          await loadClipboardHistory();
-        } catch (err) {
-          showToast('Import failed', 'error');
+  if (importBtn) {
+    importBtn.addEventListener('click', () => runTransfer(importBtn, async () => {
+      const path = await dialog.open({
+        multiple: false,
+        directory: false,
+        filters: [{ name: 'ClipCrab export', extensions: ['zst', 'ndjson', 'jsonl', 'json'] }],
+      });
+      if (!path) return;
+
+      try {
+        const report = await invoke('import_history_file', { path });
+        let message = `Imported ${report.imported} items`;
+        if (report.duplicates > 0) message += `, ${report.duplicates} duplicates skipped`;
+        if (report.rejected.length > 0) {
+          message += `, ${report.rejected.length} rejected`;
+          console.warn('Rejected import items:', report.rejected);
         }
-      };
-      reader.readAsText(file);
-    });
+        showToast(message, 'success');
+        await loadClipboardHistory();
+      } catch (err) {
+        showToast(err === 'Transfer cancelled' ? 'Import cancelled' : 'Import failed', 'error');
+        console.error('Import error:', err);
+      }
+    }));
   }
 }
 
@@ -1,14 +1,41 @@
 // Utility Functions
+let toastTimer = null;
+
 export function showToast(message, type = 'success') {
   const toast = document.getElementById('toast');
   toast.textContent = message;
   toast.className = `toast ${type} show`;
   
-  setTimeout(() => {
+  clearTimeout(toastTimer);
+  toastTimer = setTimeout(() => {
     toast.classList.remove('show');
   }, 3000);
 }
 
+// Geri al düğmeli bildirim; silinen öğeler çöp kutusundan geri getirilebilir
+export function showUndoToast(message, undoLabel, onUndo) {
+  const toast = document.getElementById('toast');
+  toast.textContent = '';
+  toast.className = 'toast success show';
+
+  const text = document.createElement('span');
+  text.textContent = message;
+  const undoBtn = document.createElement('button');
+  undoBtn.className = 'toast-action';
+  undoBtn.textContent = undoLabel;
+  undoBtn.addEventListener('click', () => {
+    clearTimeout(toastTimer);
+    toast.classList.remove('show');
+    onUndo();
+  });
+  toast.append(text, undoBtn);
+
+  clearTimeout(toastTimer);
+  toastTimer = setTimeout(() => {
+    toast.classList.remove('show');
+  }, 6000);
+}
+
 export async function waitForI18n() {
   while (!window.i18n) {
     await new Promise(r => setTimeout(r, 10));